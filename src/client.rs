@@ -1,27 +1,302 @@
-use std::ops::{DivAssign, MulAssign};
+use std::marker::PhantomData;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
 
 use cosmrs::proto::cosmos::auth::v1beta1::{
     BaseAccount, QueryAccountRequest, QueryAccountResponse,
 };
-use cosmrs::proto::cosmos::tx::v1beta1::SimulateResponse;
+use cosmrs::proto::cosmos::base::abci::v1beta1::GasInfo;
+use cosmrs::proto::cosmos::base::v1beta1::Coin;
+use cosmrs::proto::cosmos::tx::v1beta1::{GetTxResponse, SimulateResponse};
 use cosmrs::proto::cosmos::vesting::v1beta1::ContinuousVestingAccount;
 use cosmrs::proto::prost::Message;
-use cosmrs::tx::Body;
+use cosmrs::rpc::endpoint::abci_query::AbciQuery;
+use cosmrs::rpc::event::Event;
+use cosmrs::tendermint::block::Height;
+use cosmrs::tx::{Body, BodyBuilder, Fee};
+use cosmrs::Any;
+use futures::Stream;
 
+use crate::clock::{Clock, SystemClock};
+use crate::cosmos::consensus::Consensus;
+use crate::cosmos::feegrant::Feegrant;
+use crate::cosmos::feemarket::FeeMarket;
+use crate::cosmos::node::Node;
+use crate::cosmos::tendermint::Tendermint;
+use crate::cosmos::tx::{Tx, POLL_INTERVAL};
+use crate::decimal::{parse_decimal, DEC_ONE};
 use crate::error::{CosmosResult, Error};
-use crate::rpc::types::{Rpc, TxAsyncResponse, TxSyncResponse};
-use crate::rpc::{grpc::Grpc, json_rpc::JsonRpc};
+use crate::pagination::paginate_all;
+use crate::rpc::grpc::Grpc;
+use crate::rpc::json_rpc::{AbciQueryOptions, JsonRpc};
+use crate::rpc::retry::RetryConfig;
+use crate::rpc::types::{is_not_found_error, Rpc, TxAsyncResponse, TxSyncResponse};
 use crate::signer::Signer;
+use crate::tx::{check_tx_result, encode_unsigned_with_key, parse_sequence_mismatch};
 
 /// Represents a Cosmos client that can interact with the blockchain using different RPC protocols.
 #[derive(Debug, Clone)]
 pub struct CosmosClient<T: Rpc + Clone + Send + Sync> {
-    /// The chain ID for the Cosmos blockchain.
-    chain_id: String,
+    /// The chain ID for the Cosmos blockchain, required for signing only.
+    chain_id: Option<String>,
     /// The underlying RPC implementation used by the client.
     rpc: T,
     /// The signer used for transaction signing.
     signer: Option<Signer>,
+    /// How the gas limit of transactions is determined.
+    gas_strategy: GasStrategy,
+    /// The clock used for time-dependent logic such as polling.
+    clock: Arc<dyn Clock>,
+    /// Maximum size in bytes of a signed transaction, checked before broadcasting if set.
+    tx_size_limit: Option<u64>,
+    /// Memo applied to transactions whose body does not set one.
+    default_memo: Option<String>,
+    /// Number of blocks after the current height at which transactions without an explicit
+    /// timeout height expire, if set.
+    timeout_height_offset: Option<u64>,
+    /// Last known account number and sequence of the signer, if caching is enabled.
+    account_cache: Option<AccountCache>,
+    /// Preferred fee denoms when fees are computed from the chain's dynamic gas price.
+    dynamic_fee_denoms: Option<Vec<String>>,
+    /// Chain ID reported by the node, fetched once and shared between clones.
+    node_chain_id: Arc<Mutex<Option<String>>>,
+    /// Whether the configured chain ID is checked against the node's before signing.
+    verify_chain_id: bool,
+}
+
+/// How the client determines the gas limit of the transactions it signs.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GasStrategy {
+    /// Simulates each transaction and multiplies the gas used by `adjustment`, or by the
+    /// signer's `gas_adjustment` if `None`.
+    Simulate { adjustment: Option<f64> },
+    /// Uses the given gas limit for every transaction, skipping simulation.
+    Fixed(u64),
+}
+
+impl Default for GasStrategy {
+    fn default() -> Self {
+        GasStrategy::Simulate { adjustment: None }
+    }
+}
+
+/// Gas and fee estimate of a transaction, as returned by `CosmosClient::estimate_gas`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GasEstimate {
+    /// Gas limit the transaction would be signed with under the client's gas strategy.
+    pub gas_wanted: u64,
+    /// Gas used by the simulated transaction.
+    pub gas_used: u64,
+    /// Simulated gas usage increased by the gas adjustment.
+    pub adjusted_gas: u64,
+    /// Fee the transaction would pay for `gas_wanted`.
+    pub fee: Vec<Coin>,
+}
+
+/// Account number and sequence of the signer, shared between clones of a client so that every
+/// module sees the locally advanced sequence.
+#[derive(Debug, Clone, Default)]
+struct AccountCache(Arc<Mutex<Option<(u64, u64)>>>);
+
+impl AccountCache {
+    fn get(&self) -> Option<(u64, u64)> {
+        *self.0.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn set(&self, account: Option<(u64, u64)>) {
+        *self.0.lock().unwrap_or_else(|e| e.into_inner()) = account;
+    }
+
+    fn advance(&self) {
+        if let Some((_, sequence)) = self.0.lock().unwrap_or_else(|e| e.into_inner()).as_mut() {
+            *sequence += 1;
+        }
+    }
+
+    fn advance_past(&self, used: u64) {
+        if let Some((_, sequence)) = self.0.lock().unwrap_or_else(|e| e.into_inner()).as_mut() {
+            *sequence = (*sequence).max(used + 1);
+        }
+    }
+}
+
+/// Builder collecting the configuration of a `CosmosClient` before connecting it.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::time::Duration;
+///
+/// use cosmos_client::client::CosmosClientBuilder;
+/// use cosmos_client::error::CosmosResult;
+/// use cosmos_client::rpc::retry::RetryConfig;
+/// use cosmos_client::signer::Signer;
+/// use rust_decimal::Decimal;
+///
+/// # async fn example(mnemonic: &str) -> CosmosResult<()> {
+/// let signer = Signer::from_mnemonic(mnemonic, "cosmos", "uatom", None, 1.3, Decimal::new(25, 3))?;
+/// let client = CosmosClientBuilder::grpc("http://localhost:9090")
+///     .chain_id("cosmoshub-4")
+///     .timeout(Duration::from_secs(10))
+///     .retry(RetryConfig::default())
+///     .signer(signer)
+///     .build()
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct CosmosClientBuilder<T: Rpc + Clone + Send + Sync> {
+    endpoint: String,
+    chain_id: Option<String>,
+    signer: Option<Signer>,
+    timeout: Option<Duration>,
+    retry: Option<RetryConfig>,
+    path_prefix: String,
+    #[cfg(feature = "grpc-web")]
+    grpc_web: bool,
+    gas_strategy: GasStrategy,
+    default_memo: Option<String>,
+    verify_chain_id: bool,
+    transport: PhantomData<T>,
+}
+
+impl CosmosClientBuilder<JsonRpc> {
+    /// Starts building a client connecting to a JSON-RPC endpoint.
+    pub fn json_rpc(endpoint: &str) -> Self {
+        Self::new(endpoint)
+    }
+
+    /// Creates the configured client.
+    pub fn build(self) -> CosmosResult<CosmosClient<JsonRpc>> {
+        let mut rpc = match self.timeout {
+            Some(timeout) => JsonRpc::new_with_timeout(&self.endpoint, timeout)?,
+            None => JsonRpc::new(&self.endpoint)?,
+        };
+        rpc.set_retry(self.retry);
+
+        Ok(self.finish(rpc))
+    }
+}
+
+impl CosmosClientBuilder<Grpc> {
+    /// Starts building a client connecting to a gRPC endpoint.
+    pub fn grpc(endpoint: &str) -> Self {
+        Self::new(endpoint)
+    }
+
+    /// Starts building a client talking gRPC-web to an endpoint. See `Grpc::new_grpc_web`.
+    #[cfg(feature = "grpc-web")]
+    pub fn grpc_web(endpoint: &str) -> Self {
+        Self {
+            grpc_web: true,
+            ..Self::new(endpoint)
+        }
+    }
+
+    /// Connects to the endpoint and creates the configured client.
+    pub async fn build(self) -> CosmosResult<CosmosClient<Grpc>> {
+        let mut rpc = self.connect().await?;
+        rpc.set_timeout(self.timeout);
+        rpc.set_retry(self.retry);
+        rpc.set_path_prefix(&self.path_prefix);
+
+        Ok(self.finish(rpc))
+    }
+
+    /// Routes every call under a path prefix, for endpoints served behind a proxy at e.g.
+    /// `https://host/cosmos-grpc`. See `Grpc::set_path_prefix`.
+    pub fn path_prefix(mut self, path_prefix: &str) -> Self {
+        self.path_prefix = path_prefix.to_owned();
+        self
+    }
+
+    /// Creates the transport, connecting to native gRPC endpoints within the timeout, if set.
+    async fn connect(&self) -> CosmosResult<Grpc> {
+        #[cfg(feature = "grpc-web")]
+        if self.grpc_web {
+            return Grpc::new_grpc_web(&self.endpoint);
+        }
+
+        match self.timeout {
+            Some(timeout) => Grpc::new_with_timeout(&self.endpoint, timeout).await,
+            None => Grpc::new(&self.endpoint).await,
+        }
+    }
+}
+
+impl<T: Rpc + Clone + Send + Sync> CosmosClientBuilder<T> {
+    fn new(endpoint: &str) -> Self {
+        Self {
+            endpoint: endpoint.to_owned(),
+            chain_id: None,
+            signer: None,
+            timeout: None,
+            retry: None,
+            path_prefix: String::new(),
+            #[cfg(feature = "grpc-web")]
+            grpc_web: false,
+            gas_strategy: GasStrategy::default(),
+            default_memo: None,
+            verify_chain_id: false,
+            transport: PhantomData,
+        }
+    }
+
+    /// Sets the chain ID used for signing. Without it, the client is read-only until
+    /// `CosmosClient::set_chain_id` is called.
+    pub fn chain_id(mut self, chain_id: &str) -> Self {
+        self.chain_id = Some(chain_id.to_owned());
+        self
+    }
+
+    /// Attaches a signer to the client.
+    pub fn signer(mut self, signer: Signer) -> Self {
+        self.signer = Some(signer);
+        self
+    }
+
+    /// Sets the deadline of the connection and of each call, after which they fail with
+    /// `Error::Timeout`.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Retries calls failing with a transient network error according to `retry`.
+    pub fn retry(mut self, retry: RetryConfig) -> Self {
+        self.retry = Some(retry);
+        self
+    }
+
+    /// Sets how the gas limit of transactions is determined.
+    pub fn gas_strategy(mut self, strategy: GasStrategy) -> Self {
+        self.gas_strategy = strategy;
+        self
+    }
+
+    /// Sets a memo applied to every transaction whose body does not already carry one.
+    pub fn default_memo(mut self, memo: &str) -> Self {
+        self.default_memo = Some(memo.to_owned());
+        self
+    }
+
+    /// Checks the chain ID against the node's before signing. See
+    /// `CosmosClient::set_verify_chain_id`.
+    pub fn verify_chain_id(mut self, enabled: bool) -> Self {
+        self.verify_chain_id = enabled;
+        self
+    }
+
+    /// Creates the client around the connected transport.
+    fn finish(self, rpc: T) -> CosmosClient<T> {
+        let mut client = CosmosClient::from_rpc(rpc, self.chain_id);
+        client.signer = self.signer;
+        client.gas_strategy = self.gas_strategy;
+        client.default_memo = self.default_memo;
+        client.verify_chain_id = self.verify_chain_id;
+        client
+    }
 }
 
 impl CosmosClient<JsonRpc> {
@@ -37,12 +312,81 @@ impl CosmosClient<JsonRpc> {
     /// A `CosmosResult` containing the initialized `CosmosClient` if successful, or an error if
     /// the initialization fails.
     pub fn with_json_rpc(endpoint: &str, chain_id: &str) -> CosmosResult<CosmosClient<JsonRpc>> {
-        let rpc = JsonRpc::new(endpoint)?;
-        Ok(Self {
-            rpc,
-            chain_id: chain_id.to_owned(),
-            signer: None,
-        })
+        CosmosClientBuilder::json_rpc(endpoint)
+            .chain_id(chain_id)
+            .build()
+    }
+
+    /// Creates a new Cosmos client with JSON-RPC protocol whose calls fail with
+    /// `Error::Timeout` if they do not complete within `timeout`.
+    pub fn with_json_rpc_timeout(
+        endpoint: &str,
+        chain_id: &str,
+        timeout: Duration,
+    ) -> CosmosResult<CosmosClient<JsonRpc>> {
+        CosmosClientBuilder::json_rpc(endpoint)
+            .chain_id(chain_id)
+            .timeout(timeout)
+            .build()
+    }
+
+    /// Creates a new Cosmos client with JSON-RPC protocol that retries calls failing with a
+    /// transient network error according to `retry`.
+    pub fn with_json_rpc_retry(
+        endpoint: &str,
+        chain_id: &str,
+        retry: RetryConfig,
+    ) -> CosmosResult<CosmosClient<JsonRpc>> {
+        CosmosClientBuilder::json_rpc(endpoint)
+            .chain_id(chain_id)
+            .retry(retry)
+            .build()
+    }
+
+    /// Sets the deadline of each JSON-RPC call, after which it fails with `Error::Timeout`.
+    /// Passing `None` removes the deadline.
+    pub fn set_timeout(&mut self, timeout: Option<Duration>) {
+        self.rpc.set_timeout(timeout);
+    }
+
+    /// Computes the ratio of gas used to the block gas limit over the last `blocks` blocks, a
+    /// gauge of network congestion used to decide when to pay a priority fee.
+    pub async fn recent_gas_utilization(&self, blocks: u64) -> CosmosResult<f64> {
+        self.rpc.recent_gas_utilization(blocks).await
+    }
+
+    /// Subscribes to the events matching a Tendermint query over the node's WebSocket
+    /// endpoint, e.g. `tm.event='Tx' AND transfer.recipient='cosmos1...'`.
+    ///
+    /// The stream reconnects when the socket drops; see `JsonRpc::subscribe`.
+    pub async fn subscribe(
+        &self,
+        query: &str,
+    ) -> CosmosResult<impl Stream<Item = CosmosResult<Event>>> {
+        self.rpc.subscribe(query).await
+    }
+
+    /// Performs a raw ABCI query, optionally at a past block height and with a Merkle proof of
+    /// the returned value, for light-client verification. See `JsonRpc::abci_query_raw`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::HeightNotAvailable` if the node no longer retains the requested height.
+    pub async fn abci_query(
+        &self,
+        path: &str,
+        data: Vec<u8>,
+        options: AbciQueryOptions,
+    ) -> CosmosResult<AbciQuery> {
+        self.rpc.abci_query_raw(path, data, options).await
+    }
+
+    /// Creates a new read-only Cosmos client with JSON-RPC protocol.
+    ///
+    /// The client can query the chain but cannot sign until a chain ID is set with
+    /// `set_chain_id`.
+    pub fn with_json_rpc_read_only(endpoint: &str) -> CosmosResult<CosmosClient<JsonRpc>> {
+        CosmosClientBuilder::json_rpc(endpoint).build()
     }
 }
 
@@ -58,16 +402,125 @@ impl CosmosClient<Grpc> {
     ///
     /// A `CosmosClient` initialized with the specified gRPC endpoint.
     pub async fn with_grpc(endpoint: &str, chain_id: &str) -> CosmosResult<CosmosClient<Grpc>> {
-        let rpc = Grpc::new(endpoint).await?;
-        Ok(Self {
-            rpc,
-            chain_id: chain_id.to_owned(),
-            signer: None,
-        })
+        CosmosClientBuilder::grpc(endpoint)
+            .chain_id(chain_id)
+            .build()
+            .await
+    }
+
+    /// Creates a new Cosmos client with gRPC protocol whose connection and calls fail with
+    /// `Error::Timeout` if they do not complete within `timeout`.
+    pub async fn with_grpc_timeout(
+        endpoint: &str,
+        chain_id: &str,
+        timeout: Duration,
+    ) -> CosmosResult<CosmosClient<Grpc>> {
+        CosmosClientBuilder::grpc(endpoint)
+            .chain_id(chain_id)
+            .timeout(timeout)
+            .build()
+            .await
+    }
+
+    /// Creates a new Cosmos client with gRPC protocol that retries calls failing with a
+    /// transient network error according to `retry`.
+    pub async fn with_grpc_retry(
+        endpoint: &str,
+        chain_id: &str,
+        retry: RetryConfig,
+    ) -> CosmosResult<CosmosClient<Grpc>> {
+        CosmosClientBuilder::grpc(endpoint)
+            .chain_id(chain_id)
+            .retry(retry)
+            .build()
+            .await
+    }
+
+    /// Sets the deadline of each gRPC call, after which it fails with `Error::Timeout`.
+    /// Passing `None` removes the deadline.
+    pub fn set_timeout(&mut self, timeout: Option<Duration>) {
+        self.rpc.set_timeout(timeout);
+    }
+
+    /// Creates a new read-only Cosmos client with gRPC protocol.
+    ///
+    /// The client can query the chain but cannot sign until a chain ID is set with
+    /// `set_chain_id`.
+    pub async fn with_grpc_read_only(endpoint: &str) -> CosmosResult<CosmosClient<Grpc>> {
+        CosmosClientBuilder::grpc(endpoint).build().await
     }
 }
 
 impl<T: Rpc + Clone + Send + Sync> CosmosClient<T> {
+    /// Creates a client with default settings around an already constructed RPC transport.
+    fn from_rpc(rpc: T, chain_id: Option<String>) -> Self {
+        Self {
+            rpc,
+            chain_id,
+            signer: None,
+            gas_strategy: GasStrategy::default(),
+            clock: Arc::new(SystemClock),
+            tx_size_limit: None,
+            default_memo: None,
+            timeout_height_offset: None,
+            account_cache: None,
+            dynamic_fee_denoms: None,
+            node_chain_id: Arc::default(),
+            verify_chain_id: false,
+        }
+    }
+
+    /// Sets a memo applied to every transaction whose body does not already carry one.
+    ///
+    /// An explicit memo passed to a module builder always takes precedence.
+    pub fn with_default_memo(mut self, memo: &str) -> Self {
+        self.default_memo = Some(memo.to_owned());
+        self
+    }
+
+    /// Sets or, with `None`, clears the memo applied to every transaction whose body does not
+    /// already carry one.
+    pub fn set_default_memo(&mut self, memo: Option<&str>) {
+        self.default_memo = memo.map(str::to_owned);
+    }
+
+    /// Retrieves the default memo, if any.
+    pub fn default_memo(&self) -> Option<&str> {
+        self.default_memo.as_deref()
+    }
+
+    /// Sets the chain ID used for signing.
+    pub fn set_chain_id(&mut self, chain_id: &str) {
+        self.chain_id = Some(chain_id.to_owned());
+    }
+
+    /// Retrieves the chain ID used for signing.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::NoChainId` if the client was constructed without a chain ID.
+    pub fn chain_id(&self) -> CosmosResult<&str> {
+        self.chain_id.as_deref().ok_or(Error::NoChainId)
+    }
+
+    /// Enables or disables checking the configured chain ID against the one reported by the
+    /// node before signing, so that a misconfigured client fails instead of producing a
+    /// signature the chain rejects.
+    ///
+    /// When enabled, the node's chain ID is fetched once with `GetNodeInfo` and shared between
+    /// clones. Disabled by default, in which case the configured chain ID is used as is.
+    pub fn set_verify_chain_id(&mut self, enabled: bool) {
+        self.verify_chain_id = enabled;
+    }
+
+    /// Queries the chain ID of the connected node and uses it for signing, e.g. for a client
+    /// created with one of the read-only constructors.
+    pub async fn detect_chain_id(&mut self) -> CosmosResult<&str> {
+        let chain_id = self.node_chain_id().await?;
+        self.chain_id = Some(chain_id);
+        self.chain_id()
+    }
+
     /// This method associates a signer with the client, providing the necessary information for
     /// transaction signing.
     pub async fn attach_signer(&mut self, signer: Signer) {
@@ -79,6 +532,204 @@ impl<T: Rpc + Clone + Send + Sync> CosmosClient<T> {
         self.signer.as_ref()
     }
 
+    /// Sets how the gas limit of transactions is determined.
+    pub fn set_gas_strategy(&mut self, strategy: GasStrategy) {
+        self.gas_strategy = strategy;
+    }
+
+    /// Retrieves how the gas limit of transactions is determined.
+    pub fn gas_strategy(&self) -> GasStrategy {
+        self.gas_strategy
+    }
+
+    /// Sets a gas limit applied to every transaction, disabling automatic simulation.
+    ///
+    /// This is required for nodes that reject `Simulate` requests. Passing `None` restores the
+    /// default behaviour of simulating each transaction to estimate its gas.
+    pub fn set_manual_gas(&mut self, gas: Option<u64>) {
+        self.gas_strategy = match gas {
+            Some(gas) => GasStrategy::Fixed(gas),
+            None => GasStrategy::default(),
+        };
+    }
+
+    /// Sets the number of blocks after the current height at which transactions expire.
+    ///
+    /// When set, bodies without a `timeout_height` get one at signing time, so validators drop
+    /// transactions that were not included in time instead of executing them late. Passing
+    /// `None` restores the default of transactions never expiring.
+    pub fn set_timeout_height_offset(&mut self, offset: Option<u64>) {
+        self.timeout_height_offset = offset;
+    }
+
+    /// Retrieves the configured timeout height offset, if any.
+    pub fn timeout_height_offset(&self) -> Option<u64> {
+        self.timeout_height_offset
+    }
+
+    /// Retrieves the manually configured gas limit, if any.
+    pub fn manual_gas(&self) -> Option<u64> {
+        match self.gas_strategy {
+            GasStrategy::Fixed(gas) => Some(gas),
+            GasStrategy::Simulate { .. } => None,
+        }
+    }
+
+    /// Replaces the clock used for time-dependent logic, e.g. with a `MockClock` in tests.
+    pub fn set_clock(&mut self, clock: Arc<dyn Clock>) {
+        self.clock = clock;
+    }
+
+    /// Retrieves the clock used for time-dependent logic.
+    pub fn clock(&self) -> &Arc<dyn Clock> {
+        &self.clock
+    }
+
+    /// Computes fees from the chain's current gas price instead of the signer's fixed
+    /// `gas_price`, for chains running the feemarket module or Osmosis' txfees base fee.
+    ///
+    /// The fee is paid in the first of `preferred_denoms` the chain prices, falling back to
+    /// the first priced denom. Passing `None` restores the signer's fixed gas price.
+    pub fn set_dynamic_fee(&mut self, preferred_denoms: Option<Vec<String>>) {
+        self.dynamic_fee_denoms = preferred_denoms;
+    }
+
+    /// Enables or disables caching of the signer's account number and sequence.
+    ///
+    /// When enabled, the account is queried once and its sequence is then incremented locally
+    /// after each accepted broadcast, instead of querying the account before every signature.
+    /// The cache is dropped when the chain reports a sequence mismatch, and can be re-synced
+    /// with `refresh_account`.
+    pub fn set_account_cache(&mut self, enabled: bool) {
+        self.account_cache = enabled.then(AccountCache::default);
+    }
+
+    /// Re-queries the signer's account number and sequence, updating the account cache if
+    /// enabled.
+    pub async fn refresh_account(&self) -> CosmosResult<(u64, u64)> {
+        let account = self.query_account_sequence_id().await?;
+        if let Some(cache) = &self.account_cache {
+            cache.set(Some(account));
+        }
+        Ok(account)
+    }
+
+    /// Sets the maximum size of a signed transaction. Larger transactions are rejected locally
+    /// with `Error::TxTooLarge` instead of being broadcast.
+    pub fn set_tx_size_limit(&mut self, limit: Option<u64>) {
+        self.tx_size_limit = limit;
+    }
+
+    /// Loads the transaction size limit from the chain's consensus params `max_bytes`.
+    pub async fn load_tx_size_limit(&mut self) -> CosmosResult<u64> {
+        let limit = Consensus::new(self.clone()).max_block_bytes().await?;
+        self.tx_size_limit = Some(limit);
+        Ok(limit)
+    }
+
+    /// Computes the smallest fee the connected node accepts for the given gas limit, based on
+    /// its configured `minimum-gas-prices`.
+    ///
+    /// # Returns
+    ///
+    /// One coin per configured denomination, each sufficient on its own, with the amount
+    /// rounded up. An empty list means the node accepts zero-fee transactions.
+    pub async fn minimum_fee_for_gas(&self, gas_limit: u64) -> CosmosResult<Vec<Coin>> {
+        let config = Node::new(self.clone()).config().await?;
+
+        config
+            .minimum_gas_price
+            .split(',')
+            .map(str::trim)
+            .filter(|price| !price.is_empty())
+            .map(|price| {
+                let split = price
+                    .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+                    .ok_or_else(|| Error::Custom(format!("Invalid gas price {price}")))?;
+                let (amount, denom) = price.split_at(split);
+                let fee = parse_decimal(amount)? * u128::from(gas_limit);
+
+                Ok(Coin {
+                    denom: denom.to_string(),
+                    amount: fee.div_ceil(DEC_ONE).to_string(),
+                })
+            })
+            .filter(|coin: &CosmosResult<Coin>| {
+                coin.as_ref().map_or(true, |coin| coin.amount != "0")
+            })
+            .collect()
+    }
+
+    /// Checks whether the chain produced a block within the given window, by comparing the
+    /// latest block's timestamp to the client's clock.
+    ///
+    /// A `false` result indicates a halted or stalled chain, or a node that stopped syncing.
+    pub async fn is_producing_blocks(&self, within: Duration) -> CosmosResult<bool> {
+        let block_time = Tendermint::new(self.clone()).latest_header().await?.time;
+
+        Ok(match self.clock.now().duration_since(block_time) {
+            Ok(elapsed) => elapsed <= within,
+            Err(_) => true,
+        })
+    }
+
+    /// Assembles several encoded messages into a single transaction body, so they are executed
+    /// atomically: if one message fails, none of them is applied.
+    ///
+    /// # Arguments
+    ///
+    /// * `msgs`: The messages, in execution order.
+    /// * `memo`: An optional memo to attach to the transaction.
+    /// * `timeout_height`: The height after which the transaction can no longer be included. If
+    ///   `None`, the client's timeout height offset applies at signing time, if configured.
+    pub fn build_tx(
+        &self,
+        msgs: Vec<Any>,
+        memo: Option<&str>,
+        timeout_height: Option<u64>,
+    ) -> CosmosResult<Body> {
+        let mut builder = BodyBuilder::new();
+        let mut builder = builder.msgs(msgs);
+
+        if let Some(memo) = memo {
+            builder = builder.memo(memo);
+        }
+
+        if let Some(height) = timeout_height {
+            builder = builder.timeout_height(Height::try_from(height)?);
+        }
+
+        Ok(builder.finish())
+    }
+
+    /// Sums the voting power of the whole validator set at the latest height, paging through
+    /// the set as needed.
+    pub async fn total_voting_power(&self) -> CosmosResult<u64> {
+        let tendermint = Tendermint::new(self.clone());
+        let validators = paginate_all(
+            |page| tendermint.latest_validator_set(Some(page)),
+            |resp| (resp.validators, resp.pagination),
+        )
+        .await?;
+        let total = validators
+            .iter()
+            .map(|validator| u64::try_from(validator.voting_power).unwrap_or_default())
+            .sum();
+
+        Ok(total)
+    }
+
+    /// Returns `Error::TxTooLarge` if the signed payload exceeds the configured size limit.
+    fn check_tx_size(&self, payload: &[u8]) -> CosmosResult<()> {
+        match self.tx_size_limit {
+            Some(limit) if payload.len() as u64 > limit => Err(Error::TxTooLarge {
+                size: payload.len(),
+                limit,
+            }),
+            _ => Ok(()),
+        }
+    }
+
     /// Asynchronously queries the blockchain at a given path with a specified message.
     /// Returns the result as a CosmosResult.
     pub async fn query<M, R>(&self, path: &str, msg: M) -> CosmosResult<R>
@@ -90,51 +741,497 @@ impl<T: Rpc + Clone + Send + Sync> CosmosClient<T> {
         self.rpc.query(path, msg).await
     }
 
+    /// Asynchronously queries the blockchain state at a past block height.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::HeightNotAvailable` if the node reports that it no longer retains the
+    /// requested height, in which case an archive node is needed.
+    pub async fn query_at_height<M, R>(&self, path: &str, msg: M, height: u64) -> CosmosResult<R>
+    where
+        Self: Sized,
+        M: Message + Default + 'static,
+        R: Message + Default + 'static,
+    {
+        self.rpc.query_at_height(path, msg, height).await
+    }
+
     /// Asynchronously simulates a transaction using the provided payload.
     /// Returns the simulation response as a CosmosResult.
     pub async fn simulate_tx(&self, body: Body) -> CosmosResult<SimulateResponse> {
-        let mut signer = self.signer.clone().ok_or(Error::NoSignerAttached)?;
-        let (account_number, sequence_id) = self.account_sequence_id().await?;
-        let tx = signer
-            .sign(&self.chain_id, account_number, sequence_id, 100u64, body)
-            .await?;
+        let body = self.apply_default_memo(body);
+        let signer = self.signer.as_ref().ok_or(Error::NoSignerAttached)?;
+        let (_, sequence_id) = self.account_sequence_id().await?;
+        // Simulation does not verify signatures, so the payload is left unsigned rather than
+        // asking the signing backend, e.g. a Ledger, to approve a throwaway transaction.
+        let tx = encode_unsigned_with_key(body, signer.signer_public_key(), sequence_id)?;
 
         self.rpc.simulate_tx(tx).await
     }
 
+    /// Estimates the gas and fee of a transaction without broadcasting it, e.g. to show the
+    /// fee to a user before they confirm.
+    ///
+    /// The transaction is simulated even if the gas strategy is `GasStrategy::Fixed`, in which
+    /// case the fixed gas limit is still the one the fee is computed for.
+    pub async fn estimate_gas(&self, body: Body) -> CosmosResult<GasEstimate> {
+        let gas_info = self.simulated_gas(&body).await?;
+        let (adjustment, fixed_gas) = match self.gas_strategy {
+            GasStrategy::Simulate { adjustment } => (adjustment, None),
+            GasStrategy::Fixed(gas) => (None, Some(gas)),
+        };
+        let adjusted_gas = self.adjusted_gas(gas_info.gas_used, adjustment)?;
+        let gas_wanted = fixed_gas.unwrap_or(adjusted_gas);
+        let fee = self.fee_for_gas(gas_wanted).await?;
+
+        Ok(GasEstimate {
+            gas_wanted,
+            gas_used: gas_info.gas_used,
+            adjusted_gas,
+            fee: fee.amount.into_iter().map(Coin::from).collect(),
+        })
+    }
+
     /// Asynchronously broadcasts a transaction without waiting for it to be included in a block.
     /// Returns the async response as a CosmosResult.
     pub async fn broadcast_tx_async(&self, body: Body) -> CosmosResult<TxAsyncResponse> {
-        let payload = self.sign_tx(body).await?;
-        self.rpc.broadcast_tx_async(payload).await
+        self.broadcast_async(body, None).await
+    }
+
+    /// Broadcasts a transaction with the given gas limit, without simulating it and without
+    /// waiting for it to be included in a block.
+    pub async fn broadcast_tx_async_with_gas(
+        &self,
+        body: Body,
+        gas_limit: u64,
+    ) -> CosmosResult<TxAsyncResponse> {
+        self.broadcast_async(body, Some(gas_limit)).await
+    }
+
+    /// Signs and broadcasts a transaction asynchronously, with the given gas limit or the one
+    /// determined by the gas strategy.
+    async fn broadcast_async(
+        &self,
+        body: Body,
+        gas_limit: Option<u64>,
+    ) -> CosmosResult<TxAsyncResponse> {
+        let payload = self.sign_tx(body, gas_limit, None).await?;
+        self.check_tx_size(&payload)?;
+        let resp = self.rpc.broadcast_tx_async(payload).await?;
+        self.advance_sequence(resp.code.is_ok());
+        Ok(resp)
     }
 
     /// Asynchronously broadcasts a transaction and waits for it to be included in a block.
     /// Returns the sync response as a CosmosResult.
+    ///
+    /// If the node rejects the transaction because of a stale account sequence, e.g. when
+    /// another transaction of the same account was broadcast concurrently, the account is
+    /// re-queried and the transaction re-signed and rebroadcast once.
+    ///
+    /// A transaction rejected by the node fails with the typed error of `check_tx_result`.
     pub async fn broadcast_tx_sync(&self, body: Body) -> CosmosResult<TxSyncResponse> {
-        let payload = self.sign_tx(body).await?;
-        self.rpc.broadcast_tx_sync(payload).await
+        self.broadcast_sync(body, None).await
+    }
+
+    /// Broadcasts a transaction with the given gas limit, without simulating it, and waits for
+    /// it to be included in a block.
+    ///
+    /// The fee is still computed from the signer's gas price, unlike `broadcast_tx_sync_fixed`.
+    pub async fn broadcast_tx_sync_with_gas(
+        &self,
+        body: Body,
+        gas_limit: u64,
+    ) -> CosmosResult<TxSyncResponse> {
+        self.broadcast_sync(body, Some(gas_limit)).await
     }
 
-    /// Asynchronously signs a transaction using the provided `Body`.
-    async fn sign_tx(&self, body: Body) -> CosmosResult<Vec<u8>> {
-        let simulate_response = self.simulate_tx(body.clone()).await?;
-        if simulate_response.gas_info.is_none() {
-            return Err(Error::CannotSimulateTxGas);
+    /// Signs and broadcasts a transaction synchronously, with the given gas limit or the one
+    /// determined by the gas strategy.
+    async fn broadcast_sync(
+        &self,
+        body: Body,
+        gas_limit: Option<u64>,
+    ) -> CosmosResult<TxSyncResponse> {
+        let payload = self.sign_tx(body.clone(), gas_limit, None).await?;
+        self.check_tx_size(&payload)?;
+        let mut resp = self.rpc.broadcast_tx_sync(payload).await?;
+
+        if let Some((expected, _)) =
+            parse_sequence_mismatch(&resp.codespace, resp.code.value(), &resp.log)
+        {
+            // The account query returns committed state, which is what produced the stale
+            // sequence, so re-sign with the sequence the chain reported instead.
+            self.reset_sequence(expected);
+            let payload = self.sign_tx(body, gas_limit, Some(expected)).await?;
+            resp = self.rpc.broadcast_tx_sync(payload).await?;
         }
 
+        self.advance_sequence(resp.code.is_ok());
+        check_tx_result(&resp.codespace, resp.code.value(), &resp.log)?;
+        Ok(resp)
+    }
+
+    /// Broadcasts a transaction, then polls for it until it is included in a block or
+    /// `timeout` elapses.
+    ///
+    /// # Errors
+    ///
+    /// * Returns the typed errors of `check_tx_result` if the node rejects the transaction or
+    ///   it fails on execution.
+    /// * Returns `Error::Timeout` if the transaction is not included within `timeout`.
+    /// * Returns the error of the transaction query if it fails for any other reason than the
+    ///   transaction not being found yet.
+    pub async fn broadcast_and_confirm(
+        &self,
+        body: Body,
+        timeout: Duration,
+    ) -> CosmosResult<GetTxResponse> {
+        let hash = self.broadcast_tx_sync(body).await?.hash.to_string();
+        let tx = Tx::new(self.clone());
+        let deadline = self.clock.now() + timeout;
+
+        loop {
+            match tx.get_tx(&hash).await {
+                Ok(resp) => {
+                    if let Some(tx_resp) = &resp.tx_response {
+                        check_tx_result(&tx_resp.codespace, tx_resp.code, &tx_resp.raw_log)?;
+                    }
+                    return Ok(resp);
+                }
+                Err(e) if is_not_found_error(&e) => {}
+                Err(e) => return Err(e),
+            }
+
+            let remaining = deadline
+                .duration_since(self.clock.now())
+                .map_err(|_| Error::Timeout(timeout))?;
+            if remaining.is_zero() {
+                return Err(Error::Timeout(timeout));
+            }
+            self.clock.sleep(remaining.min(POLL_INTERVAL)).await;
+        }
+    }
+
+    /// Broadcasts a transaction signed with exactly the supplied gas limit and fee, bypassing
+    /// both simulation and the signer's gas price computation.
+    pub async fn broadcast_tx_sync_fixed(
+        &self,
+        body: Body,
+        gas_limit: u64,
+        fee: Vec<cosmrs::Coin>,
+    ) -> CosmosResult<TxSyncResponse> {
+        let body = self.apply_default_memo(body);
+        let body = self.apply_timeout_height(body).await?;
+        let mut signer = self.signer.clone().ok_or(Error::NoSignerAttached)?;
+        let (account_number, sequence_id) = self.account_sequence_id().await?;
+        let fee = Fee {
+            amount: fee,
+            gas_limit,
+            payer: None,
+            granter: None,
+        };
+        let payload = signer
+            .sign_with_fee(
+                &self.signing_chain_id().await?,
+                account_number,
+                sequence_id,
+                fee,
+                body,
+            )
+            .await?;
+
+        self.check_tx_size(&payload)?;
+        let resp = self.rpc.broadcast_tx_sync(payload).await?;
+        self.advance_sequence(resp.code.is_ok());
+        check_tx_result(&resp.codespace, resp.code.value(), &resp.log)?;
+        Ok(resp)
+    }
+
+    /// Broadcasts a transaction whose fee is paid by `granter` through an existing fee
+    /// allowance.
+    ///
+    /// # Arguments
+    ///
+    /// * `body` - The transaction body.
+    /// * `granter` - The account paying the fee.
+    /// * `verify_allowance` - Whether to first check that `granter` granted an allowance to the
+    ///   attached signer, failing with `Error::NoFeeAllowance` otherwise.
+    pub async fn broadcast_with_granter(
+        &self,
+        body: Body,
+        granter: &str,
+        verify_allowance: bool,
+    ) -> CosmosResult<TxSyncResponse> {
         let mut signer = self.signer.clone().ok_or(Error::NoSignerAttached)?;
-        let mut gas_info = simulate_response.gas_info.unwrap_or_default().gas_used;
+        let grantee = signer.public_address.to_string();
+        if verify_allowance {
+            let no_allowance = || Error::NoFeeAllowance {
+                granter: granter.to_string(),
+                grantee: grantee.clone(),
+            };
+            match Feegrant::new(self.clone())
+                .allowance(granter, &grantee)
+                .await
+            {
+                Ok(resp) if resp.allowance.is_some() => {}
+                Ok(_) => return Err(no_allowance()),
+                Err(e) if is_not_found_error(&e) => return Err(no_allowance()),
+                Err(e) => return Err(e),
+            }
+        }
 
-        gas_info.mul_assign(100u64 + u64::from(signer.gas_adjustment_percent));
-        gas_info.div_assign(100);
+        let body = self.apply_default_memo(body);
+        let body = self.apply_timeout_height(body).await?;
+        let gas = self.gas_limit(&body).await?;
+        let mut fee = self.fee_for_gas(gas).await?;
+        fee.granter = Some(granter.parse()?);
 
         let (account_number, sequence_id) = self.account_sequence_id().await?;
+        let payload = signer
+            .sign_with_fee(
+                &self.signing_chain_id().await?,
+                account_number,
+                sequence_id,
+                fee,
+                body,
+            )
+            .await?;
+
+        self.check_tx_size(&payload)?;
+        let resp = self.rpc.broadcast_tx_sync(payload).await?;
+        self.advance_sequence(resp.code.is_ok());
+        check_tx_result(&resp.codespace, resp.code.value(), &resp.log)?;
+        Ok(resp)
+    }
+
+    /// Replaces a pending transaction by re-signing `body` with the sequence of the stuck
+    /// transaction and a higher fee, then broadcasting it.
+    ///
+    /// # Arguments
+    ///
+    /// * `original_sequence` - The sequence number used by the stuck transaction.
+    /// * `body` - The replacement body. Reuse the original body to speed it up, or send an
+    ///   empty-effect body (e.g. a zero-amount self send) to cancel it.
+    /// * `fee` - The bumped fee, which must be higher than the original for nodes to accept it.
+    ///
+    /// Once the replacement is accepted, the signer's next transaction uses a sequence past
+    /// `original_sequence`.
+    pub async fn replace_tx(
+        &self,
+        original_sequence: u64,
+        body: Body,
+        fee: Fee,
+    ) -> CosmosResult<TxSyncResponse> {
+        let body = self.apply_default_memo(body);
+        let body = self.apply_timeout_height(body).await?;
+        let mut signer = self.signer.clone().ok_or(Error::NoSignerAttached)?;
+        let (account_number, _) = self.account_sequence_id().await?;
+        let payload = signer
+            .sign_with_fee(
+                &self.signing_chain_id().await?,
+                account_number,
+                original_sequence,
+                fee,
+                body,
+            )
+            .await?;
+
+        self.check_tx_size(&payload)?;
+        let resp = self.rpc.broadcast_tx_sync(payload).await?;
+        if resp.code.is_ok() {
+            self.advance_sequence_past(original_sequence);
+        }
+        check_tx_result(&resp.codespace, resp.code.value(), &resp.log)?;
+        Ok(resp)
+    }
+
+    /// Moves the signer's sequence lane and the cached sequence, if any, past `sequence` once a
+    /// transaction signed with it was accepted. Sequences already past it are left unchanged.
+    fn advance_sequence_past(&self, sequence: u64) {
+        if let Some(lane) = self.signer.as_ref().and_then(|s| s.sequence_lane.as_ref()) {
+            lane.advance_past(sequence);
+        }
+        if let Some(cache) = &self.account_cache {
+            cache.advance_past(sequence);
+        }
+    }
+
+    /// Advances the signer's sequence lane and the cached sequence, if any, once a transaction
+    /// was accepted.
+    fn advance_sequence(&self, accepted: bool) {
+        if !accepted {
+            return;
+        }
+        if let Some(lane) = self.signer.as_ref().and_then(|s| s.sequence_lane.as_ref()) {
+            lane.advance();
+        }
+        if let Some(cache) = &self.account_cache {
+            cache.advance();
+        }
+    }
+
+    /// Moves the signer's sequence lane and cached account, if any, to the sequence the chain
+    /// expects.
+    fn reset_sequence(&self, sequence: u64) {
+        if let Some(lane) = self.signer.as_ref().and_then(|s| s.sequence_lane.as_ref()) {
+            lane.reset(sequence);
+        }
+        if let Some(cache) = &self.account_cache {
+            let account = cache
+                .get()
+                .map(|(account_number, _)| (account_number, sequence));
+            cache.set(account);
+        }
+    }
+
+    /// Returns the chain ID reported by the node, querying it on first use.
+    async fn node_chain_id(&self) -> CosmosResult<String> {
+        if let Some(chain_id) = self
+            .node_chain_id
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+        {
+            return Ok(chain_id);
+        }
+
+        let chain_id = Tendermint::new(self.clone())
+            .node_info()
+            .await?
+            .default_node_info
+            .map(|info| info.network)
+            .filter(|network| !network.is_empty())
+            .ok_or_else(|| Error::Custom("Node did not report its chain ID".to_string()))?;
+        *self.node_chain_id.lock().unwrap_or_else(|e| e.into_inner()) = Some(chain_id.clone());
+
+        Ok(chain_id)
+    }
+
+    /// Returns the configured chain ID, after checking that it matches the node's if
+    /// `set_verify_chain_id` enabled it.
+    ///
+    /// # Errors
+    ///
+    /// * Returns `Error::NoChainId` if no chain ID is configured.
+    /// * Returns `Error::ChainIdMismatch` if the check is enabled and the chain IDs differ.
+    async fn signing_chain_id(&self) -> CosmosResult<String> {
+        let configured = self.chain_id()?.to_owned();
+        if !self.verify_chain_id {
+            return Ok(configured);
+        }
+
+        let node = self.node_chain_id().await?;
+        if configured != node {
+            return Err(Error::ChainIdMismatch { configured, node });
+        }
+
+        Ok(configured)
+    }
+
+    /// Sets the default memo on a body that does not carry an explicit one.
+    fn apply_default_memo(&self, mut body: Body) -> Body {
+        if let Some(memo) = &self.default_memo {
+            if body.memo.is_empty() {
+                body.memo = memo.clone();
+            }
+        }
+        body
+    }
+
+    /// Sets the timeout height of a body that has none to the current height plus the
+    /// configured offset.
+    async fn apply_timeout_height(&self, mut body: Body) -> CosmosResult<Body> {
+        let Some(offset) = self.timeout_height_offset else {
+            return Ok(body);
+        };
+        if body.timeout_height.value() != 0 {
+            return Ok(body);
+        }
+
+        let height = Tendermint::new(self.clone()).latest_header().await?.height;
+        body.timeout_height = Height::try_from(height + offset)?;
+        Ok(body)
+    }
+
+    /// Asynchronously signs a transaction using the provided `Body`, with the given gas limit
+    /// or the one determined by the gas strategy, and the given sequence or the signer's
+    /// current one.
+    async fn sign_tx(
+        &self,
+        body: Body,
+        gas_limit: Option<u64>,
+        sequence: Option<u64>,
+    ) -> CosmosResult<Vec<u8>> {
+        let body = self.apply_default_memo(body);
+        let body = self.apply_timeout_height(body).await?;
+        let gas_info = match gas_limit {
+            Some(gas_limit) => gas_limit,
+            None => self.gas_limit(&body).await?,
+        };
+
+        let mut signer = self.signer.clone().ok_or(Error::NoSignerAttached)?;
+        let (account_number, current) = self.account_sequence_id().await?;
+        let sequence_id = sequence.unwrap_or(current);
+        let fee = self.fee_for_gas(gas_info).await?;
         signer
-            .sign(&self.chain_id, account_number, sequence_id, gas_info, body)
+            .sign_with_fee(
+                &self.signing_chain_id().await?,
+                account_number,
+                sequence_id,
+                fee,
+                body,
+            )
             .await
     }
 
+    /// Builds the fee for the given gas limit, from the chain's dynamic gas price if enabled,
+    /// otherwise from the signer's gas prices.
+    async fn fee_for_gas(&self, gas_limit: u64) -> CosmosResult<Fee> {
+        let signer = self.signer.as_ref().ok_or(Error::NoSignerAttached)?;
+        match &self.dynamic_fee_denoms {
+            Some(denoms) => {
+                let amount = FeeMarket::new(self.clone())
+                    .fee_for_gas(gas_limit, denoms)
+                    .await?;
+                Ok(Fee::from_amount_and_gas(
+                    cosmrs::Coin::try_from(amount)?,
+                    gas_limit,
+                ))
+            }
+            None => signer.fee(gas_limit),
+        }
+    }
+
+    /// Determines the gas limit of a transaction according to the gas strategy, either the
+    /// fixed one or the simulated gas usage increased by the gas adjustment.
+    async fn gas_limit(&self, body: &Body) -> CosmosResult<u64> {
+        let adjustment = match self.gas_strategy {
+            GasStrategy::Fixed(gas) => return Ok(gas),
+            GasStrategy::Simulate { adjustment } => adjustment,
+        };
+
+        let gas_info = self.simulated_gas(body).await?;
+        self.adjusted_gas(gas_info.gas_used, adjustment)
+    }
+
+    /// Simulates a transaction and returns its gas usage.
+    async fn simulated_gas(&self, body: &Body) -> CosmosResult<GasInfo> {
+        self.simulate_tx(body.clone())
+            .await?
+            .gas_info
+            .ok_or(Error::CannotSimulateTxGas)
+    }
+
+    /// Increases a simulated gas usage by `adjustment`, or by the signer's gas adjustment if
+    /// `None`, rounding up.
+    fn adjusted_gas(&self, gas_used: u64, adjustment: Option<f64>) -> CosmosResult<u64> {
+        let signer = self.signer.as_ref().ok_or(Error::NoSignerAttached)?;
+        let adjustment = adjustment.unwrap_or(signer.gas_adjustment);
+
+        Ok((gas_used as f64 * adjustment).ceil() as u64)
+    }
+
     /// Asynchronously updates the client's sequence ID and account ID from the blockchain.
     ///
     /// This method queries the blockchain to obtain the latest account information, including
@@ -145,6 +1242,23 @@ impl<T: Rpc + Clone + Send + Sync> CosmosClient<T> {
     ///
     /// A `CosmosResult` indicating the success of the operation or an error if any.
     async fn account_sequence_id(&self) -> CosmosResult<(u64, u64)> {
+        let signer = self.signer.as_ref().ok_or(Error::NoSignerAttached)?;
+        let (account_number, sequence) = match &self.account_cache {
+            Some(cache) => match cache.get() {
+                Some(account) => account,
+                None => self.refresh_account().await?,
+            },
+            None => self.query_account_sequence_id().await?,
+        };
+
+        match &signer.sequence_lane {
+            Some(lane) => Ok((account_number, lane.peek())),
+            None => Ok((account_number, sequence)),
+        }
+    }
+
+    /// Queries the signer's account number and on-chain sequence.
+    async fn query_account_sequence_id(&self) -> CosmosResult<(u64, u64)> {
         let signer = self.signer.clone().ok_or(Error::NoSignerAttached)?;
 
         let query = QueryAccountRequest {
@@ -159,10 +1273,10 @@ impl<T: Rpc + Clone + Send + Sync> CosmosClient<T> {
             address: signer.public_address.to_string(),
         })?;
 
-        match account.type_url.as_str() {
+        let (account_number, sequence) = match account.type_url.as_str() {
             "/cosmos.auth.v1beta1.BaseAccount" => {
                 let account = BaseAccount::decode(account.value.as_slice())?;
-                return Ok((account.account_number, account.sequence));
+                (account.account_number, account.sequence)
             }
             "/cosmos.vesting.v1beta1.ContinuousVestingAccount" => {
                 let account = ContinuousVestingAccount::decode(account.value.as_slice())?;
@@ -171,13 +1285,164 @@ impl<T: Rpc + Clone + Send + Sync> CosmosClient<T> {
                     .ok_or(Error::NoVestingBaseAccount)?
                     .base_account
                     .ok_or(Error::NoVestingBaseAccount)?;
-                return Ok((account.account_number, account.sequence));
+                (account.account_number, account.sequence)
+            }
+            _ => {
+                return Err(Error::AccountDoesNotExist {
+                    address: signer.public_address.to_string(),
+                })
             }
-            _ => {}
+        };
+
+        Ok((account_number, sequence))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use async_trait::async_trait;
+    use bytes::Bytes;
+    use cosmrs::tendermint::abci::Code;
+    use cosmrs::tendermint::Hash;
+    use rust_decimal::Decimal;
+
+    use super::*;
+
+    const PRIVATE_KEY: &str = "0101010101010101010101010101010101010101010101010101010101010101";
+
+    /// Transport accepting every broadcast and failing every other call.
+    #[derive(Clone)]
+    struct AcceptingRpc;
+
+    #[async_trait]
+    impl Rpc for AcceptingRpc {
+        async fn query<M, R>(&self, path: &str, _msg: M) -> CosmosResult<R>
+        where
+            Self: Sized,
+            M: Message + Default + 'static,
+            R: Message + Default + 'static,
+        {
+            Err(Error::Custom(format!("unexpected query {path}")))
         }
 
-        Err(Error::AccountDoesNotExist {
-            address: signer.public_address.to_string(),
-        })
+        async fn query_at_height<M, R>(&self, path: &str, _msg: M, _height: u64) -> CosmosResult<R>
+        where
+            Self: Sized,
+            M: Message + Default + 'static,
+            R: Message + Default + 'static,
+        {
+            Err(Error::Custom(format!("unexpected query {path}")))
+        }
+
+        async fn simulate_tx(&self, _payload: Vec<u8>) -> CosmosResult<SimulateResponse> {
+            Err(Error::Custom("unexpected simulation".to_string()))
+        }
+
+        async fn broadcast_tx_sync(&self, _payload: Vec<u8>) -> CosmosResult<TxSyncResponse> {
+            Ok(TxSyncResponse {
+                codespace: String::new(),
+                code: Code::Ok,
+                data: Bytes::new(),
+                log: String::new(),
+                hash: Hash::default(),
+            })
+        }
+
+        async fn broadcast_tx_async(&self, _payload: Vec<u8>) -> CosmosResult<TxAsyncResponse> {
+            Err(Error::Custom("unexpected broadcast".to_string()))
+        }
+    }
+
+    async fn client(sequence: u64) -> CosmosClient<AcceptingRpc> {
+        let signer = Signer::from_private_key(
+            PRIVATE_KEY,
+            "cosmos",
+            "uatom",
+            1.3,
+            Decimal::from_str("0.025").unwrap(),
+        )
+        .unwrap()
+        .ephemeral(sequence);
+
+        let mut client = CosmosClient::from_rpc(AcceptingRpc, Some("test-1".to_string()));
+        client.attach_signer(signer).await;
+        client.set_account_cache(true);
+        if let Some(cache) = &client.account_cache {
+            cache.set(Some((7, sequence)));
+        }
+        client
+    }
+
+    fn next_sequences(client: &CosmosClient<AcceptingRpc>) -> (u64, u64) {
+        let lane = client.signer().unwrap().sequence_lane.as_ref().unwrap();
+        let (_, cached) = client.account_cache.as_ref().unwrap().get().unwrap();
+        (lane.peek(), cached)
+    }
+
+    fn fee() -> Fee {
+        Fee::from_amount_and_gas(
+            cosmrs::Coin {
+                amount: 10_000,
+                denom: "uatom".parse().unwrap(),
+            },
+            200_000u64,
+        )
+    }
+
+    #[tokio::test]
+    async fn replace_tx_advances_past_the_replaced_sequence() {
+        let client = client(5).await;
+
+        client
+            .replace_tx(5, BodyBuilder::new().finish(), fee())
+            .await
+            .unwrap();
+
+        assert_eq!(next_sequences(&client), (6, 6));
+    }
+
+    #[tokio::test]
+    async fn replace_tx_keeps_sequences_already_past_it() {
+        let client = client(5).await;
+
+        client
+            .replace_tx(3, BodyBuilder::new().finish(), fee())
+            .await
+            .unwrap();
+
+        assert_eq!(next_sequences(&client), (5, 5));
+    }
+
+    #[tokio::test]
+    async fn broadcast_and_confirm_propagates_query_errors() {
+        let mut client = client(5).await;
+        client.set_gas_strategy(GasStrategy::Fixed(200_000));
+
+        let res = client
+            .broadcast_and_confirm(BodyBuilder::new().finish(), Duration::from_secs(60))
+            .await;
+
+        assert!(matches!(res, Err(Error::Custom(_))));
+    }
+
+    #[tokio::test]
+    async fn signing_uses_the_configured_chain_id_without_querying_the_node() {
+        let client = client(5).await;
+
+        assert_eq!(client.signing_chain_id().await.unwrap(), "test-1");
+    }
+
+    #[tokio::test]
+    async fn signing_rejects_a_chain_id_mismatch_when_verifying() {
+        let mut client = client(5).await;
+        client.set_verify_chain_id(true);
+        *client.node_chain_id.lock().unwrap() = Some("other-1".to_string());
+
+        assert!(matches!(
+            client.signing_chain_id().await,
+            Err(Error::ChainIdMismatch { .. })
+        ));
     }
 }
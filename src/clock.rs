@@ -0,0 +1,95 @@
+use std::fmt::Debug;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime};
+
+use async_trait::async_trait;
+
+/// Source of the current time and of delays, injected wherever time-dependent logic runs.
+#[async_trait]
+pub trait Clock: Debug + Send + Sync {
+    /// Returns the current wall-clock time.
+    fn now(&self) -> SystemTime;
+
+    /// Waits for the given duration.
+    async fn sleep(&self, duration: Duration);
+}
+
+/// Clock backed by the system time and the tokio timer.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+#[async_trait]
+impl Clock for SystemClock {
+    fn now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+
+    async fn sleep(&self, duration: Duration) {
+        tokio::time::sleep(duration).await;
+    }
+}
+
+/// Manually driven clock for tests. Sleeping advances the clock instantly instead of waiting.
+#[derive(Debug, Clone)]
+pub struct MockClock {
+    now: Arc<Mutex<SystemTime>>,
+}
+
+impl MockClock {
+    /// Creates a new `MockClock` starting at the given time.
+    pub fn new(start: SystemTime) -> Self {
+        Self {
+            now: Arc::new(Mutex::new(start)),
+        }
+    }
+
+    /// Moves the clock forward by the given duration.
+    pub fn advance(&self, duration: Duration) {
+        let mut now = self.now.lock().unwrap_or_else(|e| e.into_inner());
+        *now += duration;
+    }
+
+    /// Sets the clock to the given time.
+    pub fn set(&self, time: SystemTime) {
+        *self.now.lock().unwrap_or_else(|e| e.into_inner()) = time;
+    }
+}
+
+impl Default for MockClock {
+    fn default() -> Self {
+        Self::new(SystemTime::UNIX_EPOCH)
+    }
+}
+
+#[async_trait]
+impl Clock for MockClock {
+    fn now(&self) -> SystemTime {
+        *self.now.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    async fn sleep(&self, duration: Duration) {
+        self.advance(duration);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Instant;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn mock_sleep_advances_now_without_waiting() {
+        let clock = MockClock::default();
+        let shared = clock.clone();
+        let started = Instant::now();
+
+        clock.sleep(Duration::from_secs(3600)).await;
+
+        assert!(started.elapsed() < Duration::from_secs(1));
+        assert_eq!(
+            shared.now(),
+            SystemTime::UNIX_EPOCH + Duration::from_secs(3600)
+        );
+    }
+}
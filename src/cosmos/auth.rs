@@ -4,8 +4,23 @@ pub use cosmrs::proto::cosmos::auth::v1beta1::{
 };
 pub use cosmrs::proto::cosmos::base::query::v1beta1::PageRequest;
 
+use std::io::Write;
+use std::pin::pin;
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use cosmrs::proto::cosmos::auth::v1beta1::{BaseAccount, ModuleAccount, Params};
+use cosmrs::proto::cosmos::vesting::v1beta1::{
+    ContinuousVestingAccount, DelayedVestingAccount, PeriodicVestingAccount,
+};
+use cosmrs::proto::prost::Message;
+use cosmrs::Any;
+use futures::StreamExt;
+use serde_json::{json, Value};
+
 use crate::client::CosmosClient;
-use crate::error::CosmosResult;
+use crate::error::{CosmosResult, Error};
+use crate::pagination::paginate_stream;
 use crate::rpc::types::Rpc;
 
 /// Main struct providing access to Auth module functions.
@@ -48,4 +63,93 @@ impl<T: Rpc + Clone + Send + Sync> Auth<T> {
             .query("/cosmos.auth.v1beta1.Query/Params", query)
             .await
     }
+
+    /// Fetches the maximum number of characters allowed in a transaction memo.
+    pub async fn max_memo_characters(&self) -> CosmosResult<u64> {
+        Ok(self.auth_params().await?.max_memo_characters)
+    }
+
+    /// Fetches the gas charged per byte of transaction size.
+    pub async fn tx_size_cost_per_byte(&self) -> CosmosResult<u64> {
+        Ok(self.auth_params().await?.tx_size_cost_per_byte)
+    }
+
+    /// Fetches the gas charged to verify a secp256k1 signature.
+    pub async fn sig_verify_cost_secp256k1(&self) -> CosmosResult<u64> {
+        Ok(self.auth_params().await?.sig_verify_cost_secp256k1)
+    }
+
+    /// Fetches the Auth module parameters, failing if the response carries none.
+    async fn auth_params(&self) -> CosmosResult<Params> {
+        self.params()
+            .await?
+            .params
+            .ok_or_else(|| Error::Custom("No auth params".to_string()))
+    }
+
+    /// Streams every account of the chain to `writer` as JSON lines, paging through the
+    /// accounts query until exhausted, one page at a time.
+    ///
+    /// Each line holds the account `type_url` and, for base, module and vesting accounts, its
+    /// `address`, `account_number` and `sequence`. Other account types carry their raw
+    /// base64-encoded `value` instead.
+    ///
+    /// # Returns
+    ///
+    /// The number of exported accounts.
+    pub async fn export_accounts<W: Write + Send>(&self, writer: &mut W) -> CosmosResult<u64> {
+        let accounts = paginate_stream(
+            |page| self.accounts(Some(page)),
+            |resp| (resp.accounts, resp.pagination),
+        );
+        let mut accounts = pin!(accounts);
+        let mut exported = 0;
+        while let Some(account) = accounts.next().await {
+            writeln!(writer, "{}", account_json(&account?)?)?;
+            exported += 1;
+        }
+
+        writer.flush()?;
+        Ok(exported)
+    }
+}
+
+/// Renders an account as a JSON object.
+fn account_json(account: &Any) -> CosmosResult<Value> {
+    let Some(base) = decode_base_account(account)? else {
+        return Ok(json!({
+            "type_url": account.type_url,
+            "value": STANDARD.encode(&account.value),
+        }));
+    };
+
+    Ok(json!({
+        "type_url": account.type_url,
+        "address": base.address,
+        "account_number": base.account_number,
+        "sequence": base.sequence,
+    }))
+}
+
+/// Extracts the base account of the account types known to the SDK.
+fn decode_base_account(account: &Any) -> CosmosResult<Option<BaseAccount>> {
+    let value = account.value.as_slice();
+    let base = match account.type_url.as_str() {
+        "/cosmos.auth.v1beta1.BaseAccount" => Some(BaseAccount::decode(value)?),
+        "/cosmos.auth.v1beta1.ModuleAccount" => ModuleAccount::decode(value)?.base_account,
+        "/cosmos.vesting.v1beta1.ContinuousVestingAccount" => {
+            ContinuousVestingAccount::decode(value)?
+                .base_vesting_account
+                .and_then(|account| account.base_account)
+        }
+        "/cosmos.vesting.v1beta1.DelayedVestingAccount" => DelayedVestingAccount::decode(value)?
+            .base_vesting_account
+            .and_then(|account| account.base_account),
+        "/cosmos.vesting.v1beta1.PeriodicVestingAccount" => PeriodicVestingAccount::decode(value)?
+            .base_vesting_account
+            .and_then(|account| account.base_account),
+        _ => None,
+    };
+
+    Ok(base)
 }
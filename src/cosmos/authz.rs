@@ -0,0 +1,153 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use cosmrs::proto::cosmos::authz::v1beta1::{
+    GenericAuthorization, Grant, MsgExec, MsgGrant, MsgRevoke, QueryGranteeGrantsRequest,
+    QueryGranteeGrantsResponse, QueryGrantsRequest, QueryGrantsResponse,
+};
+use cosmrs::proto::cosmos::base::query::v1beta1::PageRequest;
+use cosmrs::proto::tendermint::google::protobuf::Timestamp;
+use cosmrs::tx::Body;
+use cosmrs::Any;
+
+use crate::error::{CosmosResult, Error};
+use crate::{client::CosmosClient, rpc::types::Rpc};
+
+/// Builds an authorization allowing the grantee to execute any message of the given type on
+/// the granter's behalf, e.g. `/cosmos.bank.v1beta1.MsgSend`.
+pub fn generic_authorization(msg_type_url: &str) -> CosmosResult<Any> {
+    Ok(Any::from_msg(&GenericAuthorization {
+        msg: msg_type_url.to_string(),
+    })?)
+}
+
+/// Main struct providing access to Authz module functions.
+#[derive(Debug, Clone)]
+pub struct Authz<T: Rpc + Clone + Send + Sync> {
+    client: CosmosClient<T>,
+}
+
+impl<T: Rpc + Clone + Send + Sync> Authz<T> {
+    /// Creates a new `Authz` instance with the provided Cosmos client.
+    pub fn new(client: CosmosClient<T>) -> Self {
+        Self { client }
+    }
+
+    /// Grants `grantee` an authorization to act on behalf of the attached signer.
+    ///
+    /// # Arguments
+    ///
+    /// * `grantee`: The account receiving the authorization.
+    /// * `authorization`: The encoded authorization, e.g. from `generic_authorization` or a
+    ///   `SendAuthorization`.
+    /// * `expiration`: When the authorization expires, or never if `None`.
+    /// * `memo`: An optional memo to attach to the transaction.
+    /// * `timeout_height`: The height after which the transaction can no longer be included. If
+    ///   `None`, the client's timeout height offset applies at signing time, if configured.
+    ///
+    /// # Errors
+    ///
+    /// * Returns `Error::NoSignerAttached` if no signer is attached to the client.
+    pub async fn grant(
+        &self,
+        grantee: &str,
+        authorization: Any,
+        expiration: Option<SystemTime>,
+        memo: Option<&str>,
+        timeout_height: Option<u64>,
+    ) -> CosmosResult<Body> {
+        let signer = self.client.signer().ok_or(Error::NoSignerAttached)?;
+        let expiration = expiration.map(|time| {
+            let since_epoch = time.duration_since(UNIX_EPOCH).unwrap_or_default();
+            Timestamp {
+                seconds: i64::try_from(since_epoch.as_secs()).unwrap_or(i64::MAX),
+                nanos: i32::try_from(since_epoch.subsec_nanos()).unwrap_or_default(),
+            }
+        });
+        let msg = Any::from_msg(&MsgGrant {
+            granter: signer.public_address.to_string(),
+            grantee: grantee.to_string(),
+            grant: Some(Grant {
+                authorization: Some(authorization),
+                expiration,
+            }),
+        })?;
+
+        self.client.build_tx(vec![msg], memo, timeout_height)
+    }
+
+    /// Revokes the authorization the attached signer granted to `grantee` for a message type.
+    ///
+    /// # Errors
+    ///
+    /// * Returns `Error::NoSignerAttached` if no signer is attached to the client.
+    pub async fn revoke(
+        &self,
+        grantee: &str,
+        msg_type_url: &str,
+        memo: Option<&str>,
+        timeout_height: Option<u64>,
+    ) -> CosmosResult<Body> {
+        let signer = self.client.signer().ok_or(Error::NoSignerAttached)?;
+        let msg = Any::from_msg(&MsgRevoke {
+            granter: signer.public_address.to_string(),
+            grantee: grantee.to_string(),
+            msg_type_url: msg_type_url.to_string(),
+        })?;
+
+        self.client.build_tx(vec![msg], memo, timeout_height)
+    }
+
+    /// Wraps messages in a `MsgExec` so the attached signer executes them as grantee, on
+    /// behalf of the granters named as signers in the messages.
+    ///
+    /// # Errors
+    ///
+    /// * Returns `Error::NoSignerAttached` if no signer is attached to the client.
+    pub async fn exec(
+        &self,
+        msgs: Vec<Any>,
+        memo: Option<&str>,
+        timeout_height: Option<u64>,
+    ) -> CosmosResult<Body> {
+        let signer = self.client.signer().ok_or(Error::NoSignerAttached)?;
+        let msg = Any::from_msg(&MsgExec {
+            grantee: signer.public_address.to_string(),
+            msgs,
+        })?;
+
+        self.client.build_tx(vec![msg], memo, timeout_height)
+    }
+
+    /// Fetches the authorizations `granter` granted to `grantee`.
+    pub async fn grants(
+        &self,
+        granter: &str,
+        grantee: &str,
+        pagination: Option<PageRequest>,
+    ) -> CosmosResult<QueryGrantsResponse> {
+        let query = QueryGrantsRequest {
+            granter: granter.to_string(),
+            grantee: grantee.to_string(),
+            msg_type_url: String::new(),
+            pagination,
+        };
+        self.client
+            .query("/cosmos.authz.v1beta1.Query/Grants", query)
+            .await
+    }
+
+    /// Fetches every authorization granted to `grantee`, by any granter.
+    pub async fn grantee_grants(
+        &self,
+        grantee: &str,
+        pagination: Option<PageRequest>,
+    ) -> CosmosResult<QueryGranteeGrantsResponse> {
+        let query = QueryGranteeGrantsRequest {
+            grantee: grantee.to_string(),
+            pagination,
+        };
+        self.client
+            .query("/cosmos.authz.v1beta1.Query/GranteeGrants", query)
+            .await
+    }
+}
@@ -1,8 +1,12 @@
 use cosmrs::proto::cosmos::bank::v1beta1::{
-    MsgSend, QueryDenomMetadataRequest, QueryDenomMetadataResponse,
+    Metadata, MsgSend, QueryAllBalancesRequest, QueryAllBalancesResponse, QueryBalanceRequest,
+    QueryBalanceResponse, QueryDenomMetadataRequest, QueryDenomMetadataResponse,
+    QuerySendEnabledRequest, QuerySendEnabledResponse, QuerySupplyOfRequest, QuerySupplyOfResponse,
+    QueryTotalSupplyRequest, QueryTotalSupplyResponse,
 };
+use cosmrs::proto::cosmos::base::query::v1beta1::PageRequest;
 use cosmrs::proto::cosmos::base::v1beta1::Coin;
-use cosmrs::tx::{Body, BodyBuilder};
+use cosmrs::tx::Body;
 use cosmrs::Any;
 
 use crate::client::CosmosClient;
@@ -33,6 +37,8 @@ impl<T: Rpc + Clone + Send + Sync> Bank<T> {
     /// * `to_address`: The address to send the tokens to.
     /// * `amount`: The amount of tokens to send, denominated in various denominations.
     /// * `memo`: An optional memo to attach to the transaction.
+    /// * `timeout_height`: The height after which the transaction can no longer be included. If
+    ///   `None`, the client's timeout height offset applies at signing time, if configured.
     ///
     /// # Returns
     ///
@@ -48,21 +54,54 @@ impl<T: Rpc + Clone + Send + Sync> Bank<T> {
         to_address: &str,
         amount: Vec<Coin>,
         memo: Option<&str>,
+        timeout_height: Option<u64>,
     ) -> CosmosResult<Body> {
+        let msg = self.send_msg(to_address, amount)?;
+        self.client.build_tx(vec![msg], memo, timeout_height)
+    }
+
+    /// Encodes a `MsgSend` from the attached signer without wrapping it in a `Body`, so it can
+    /// be combined with other messages through `CosmosClient::build_tx`.
+    ///
+    /// # Errors
+    ///
+    /// * Returns `Error::NoSignerAttached` if no signer is attached to the client.
+    pub fn send_msg(&self, to_address: &str, amount: Vec<Coin>) -> CosmosResult<Any> {
         let signer = self.client.signer().ok_or(Error::NoSignerAttached)?;
-        let msg = Any::from_msg(&MsgSend {
+        Ok(Any::from_msg(&MsgSend {
             from_address: signer.public_address.to_string(),
             to_address: to_address.to_string(),
             amount,
-        })?;
-        let mut builder = BodyBuilder::new();
-        let mut builder = builder.msg(msg);
+        })?)
+    }
+
+    /// Fetches the balance of an address in a single denomination.
+    pub async fn balance(&self, address: &str, denom: &str) -> CosmosResult<QueryBalanceResponse> {
+        let query = QueryBalanceRequest {
+            address: address.to_string(),
+            denom: denom.to_string(),
+        };
+
+        self.client
+            .query("/cosmos.bank.v1beta1.Query/Balance", query)
+            .await
+    }
 
-        if let Some(memo) = memo {
-            builder = builder.memo(memo);
-        }
+    /// Fetches the balances of an address in every denomination, with optional pagination.
+    pub async fn all_balances(
+        &self,
+        address: &str,
+        pagination: Option<PageRequest>,
+    ) -> CosmosResult<QueryAllBalancesResponse> {
+        let query = QueryAllBalancesRequest {
+            address: address.to_string(),
+            pagination,
+            ..Default::default()
+        };
 
-        Ok(builder.finish())
+        self.client
+            .query("/cosmos.bank.v1beta1.Query/AllBalances", query)
+            .await
     }
 
     /// Fetches the metadata of a given token denomination from the Cosmos blockchain.
@@ -90,4 +129,113 @@ impl<T: Rpc + Clone + Send + Sync> Bank<T> {
             .query("/cosmos.bank.v1beta1.Query/DenomMetadata", query)
             .await
     }
+
+    /// Fetches whether sending is enabled for the given denominations.
+    ///
+    /// Denominations without an explicit entry follow the module's `default_send_enabled`
+    /// param and are omitted from the response.
+    pub async fn send_enabled(
+        &self,
+        denoms: Vec<String>,
+    ) -> CosmosResult<QuerySendEnabledResponse> {
+        let query = QuerySendEnabledRequest {
+            denoms,
+            pagination: None,
+        };
+
+        self.client
+            .query("/cosmos.bank.v1beta1.Query/SendEnabled", query)
+            .await
+    }
+
+    /// Fetches the send-enabled flag of every denomination with an explicit entry, with
+    /// optional pagination.
+    pub async fn all_send_enabled(
+        &self,
+        pagination: Option<PageRequest>,
+    ) -> CosmosResult<QuerySendEnabledResponse> {
+        let query = QuerySendEnabledRequest {
+            denoms: vec![],
+            pagination,
+        };
+
+        self.client
+            .query("/cosmos.bank.v1beta1.Query/SendEnabled", query)
+            .await
+    }
+
+    /// Fetches the total supply of every denomination, with optional pagination.
+    pub async fn total_supply(
+        &self,
+        pagination: Option<PageRequest>,
+    ) -> CosmosResult<QueryTotalSupplyResponse> {
+        let query = QueryTotalSupplyRequest { pagination };
+
+        self.client
+            .query("/cosmos.bank.v1beta1.Query/TotalSupply", query)
+            .await
+    }
+
+    /// Fetches the total supply of a single denomination.
+    pub async fn supply_of(&self, denom: &str) -> CosmosResult<QuerySupplyOfResponse> {
+        let query = QuerySupplyOfRequest {
+            denom: denom.to_string(),
+        };
+
+        self.client
+            .query("/cosmos.bank.v1beta1.Query/SupplyOf", query)
+            .await
+    }
+
+    /// Fetches the total supply of a denomination and converts it to its display unit.
+    ///
+    /// The exponent is taken from the denom unit matching the metadata's `display` denom.
+    ///
+    /// # Returns
+    ///
+    /// The supply as a decimal string, e.g. `"1234.567890"` for `1234567890` with exponent 6.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::Custom` if the denom has no metadata, or if none of its denom units
+    /// matches the `display` denom.
+    pub async fn circulating_supply_display(&self, denom: &str) -> CosmosResult<String> {
+        let supply = self.supply_of(denom).await?.amount.unwrap_or_default();
+        let metadata = self
+            .denom_metadata(denom)
+            .await?
+            .metadata
+            .ok_or_else(|| Error::Custom(format!("No metadata for denom {denom}")))?;
+
+        let exponent = display_exponent(&metadata).ok_or_else(|| {
+            Error::Custom(format!(
+                "No denom unit matches the display denom {} of {denom}",
+                metadata.display
+            ))
+        })?;
+
+        Ok(format_decimal(&supply.amount, exponent))
+    }
+}
+
+/// Returns the exponent of the denom unit matching the metadata's `display` denom.
+pub(crate) fn display_exponent(metadata: &Metadata) -> Option<u32> {
+    metadata
+        .denom_units
+        .iter()
+        .find(|unit| unit.denom == metadata.display)
+        .map(|unit| unit.exponent)
+}
+
+/// Formats an integer amount string as a decimal with `exponent` fractional digits.
+fn format_decimal(amount: &str, exponent: u32) -> String {
+    let amount = if amount.is_empty() { "0" } else { amount };
+    let exponent = exponent as usize;
+    if exponent == 0 {
+        return amount.to_string();
+    }
+
+    let padded = format!("{amount:0>width$}", width = exponent + 1);
+    let (int, frac) = padded.split_at(padded.len() - exponent);
+    format!("{int}.{frac}")
 }
@@ -0,0 +1,50 @@
+use cosmrs::proto::tendermint::v0_38::types::ConsensusParams;
+
+use crate::error::{CosmosResult, Error};
+use crate::{client::CosmosClient, rpc::types::Rpc};
+
+/// Request type for `/cosmos.consensus.v1.Query/Params`.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct QueryParamsRequest {}
+
+/// Response type for `/cosmos.consensus.v1.Query/Params`.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct QueryParamsResponse {
+    /// The consensus parameters of the chain.
+    #[prost(message, optional, tag = "1")]
+    pub params: Option<ConsensusParams>,
+}
+
+/// Main struct providing access to Consensus module functions.
+#[derive(Debug, Clone)]
+pub struct Consensus<T: Rpc + Clone + Send + Sync> {
+    client: CosmosClient<T>,
+}
+
+impl<T: Rpc + Clone + Send + Sync> Consensus<T> {
+    /// Creates a new `Consensus` instance with the provided Cosmos client.
+    pub fn new(client: CosmosClient<T>) -> Self {
+        Self { client }
+    }
+
+    /// Fetches the consensus parameters of the chain.
+    pub async fn params(&self) -> CosmosResult<QueryParamsResponse> {
+        let query = QueryParamsRequest {};
+        self.client
+            .query("/cosmos.consensus.v1.Query/Params", query)
+            .await
+    }
+
+    /// Fetches the maximum block size in bytes, which bounds the size of a single transaction.
+    pub async fn max_block_bytes(&self) -> CosmosResult<u64> {
+        let max_bytes = self
+            .params()
+            .await?
+            .params
+            .and_then(|params| params.block)
+            .map(|block| block.max_bytes)
+            .ok_or_else(|| Error::Custom("No block consensus params".to_string()))?;
+
+        Ok(u64::try_from(max_bytes).unwrap_or_default())
+    }
+}
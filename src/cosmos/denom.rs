@@ -0,0 +1,133 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use cosmrs::proto::cosmos::bank::v1beta1::Metadata;
+use cosmrs::proto::cosmos::base::v1beta1::Coin;
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+
+use crate::client::CosmosClient;
+use crate::cosmos::bank::{display_exponent, Bank};
+use crate::error::{CosmosResult, Error};
+use crate::rpc::types::Rpc;
+
+/// Largest exponent whose scaling factor fits in a `Decimal`.
+const MAX_EXPONENT: u32 = 28;
+
+/// Resolves denom metadata from the bank module and converts amounts between base units
+/// (e.g. `uatom`) and display units (e.g. `ATOM`).
+///
+/// Fetched metadata is cached and shared between clones of the registry. Denoms without
+/// on-chain metadata, such as many IBC denoms, can be given a fallback exponent with
+/// `register_exponent`.
+#[derive(Debug, Clone)]
+pub struct DenomRegistry<T: Rpc + Clone + Send + Sync> {
+    bank: Bank<T>,
+    metadata: Arc<Mutex<HashMap<String, Metadata>>>,
+    fallback_exponents: HashMap<String, u32>,
+}
+
+impl<T: Rpc + Clone + Send + Sync> DenomRegistry<T> {
+    /// Creates a new `DenomRegistry` with an empty cache, using the provided Cosmos client.
+    pub fn new(client: CosmosClient<T>) -> Self {
+        Self {
+            bank: Bank::new(client),
+            metadata: Arc::default(),
+            fallback_exponents: HashMap::new(),
+        }
+    }
+
+    /// Registers the exponent of a denom's display unit, used when the chain has no metadata
+    /// for the denom.
+    pub fn register_exponent(&mut self, denom: &str, exponent: u32) {
+        self.fallback_exponents.insert(denom.to_string(), exponent);
+    }
+
+    /// Fetches the metadata of a denom, from the cache if it was fetched before.
+    pub async fn metadata(&self, denom: &str) -> CosmosResult<Metadata> {
+        if let Some(metadata) = self.cached(denom) {
+            return Ok(metadata);
+        }
+
+        let metadata = self
+            .bank
+            .denom_metadata(denom)
+            .await?
+            .metadata
+            .ok_or_else(|| Error::Custom(format!("No metadata for denom {denom}")))?;
+        self.metadata
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(denom.to_string(), metadata.clone());
+
+        Ok(metadata)
+    }
+
+    /// Returns the exponent of a denom's display unit, from its metadata or, if the metadata
+    /// cannot be fetched, from the exponent registered with `register_exponent`.
+    ///
+    /// # Errors
+    ///
+    /// * Returns the metadata query error if no fallback exponent is registered.
+    /// * Returns `Error::Custom` if the metadata has no unit matching its display denom.
+    pub async fn exponent(&self, denom: &str) -> CosmosResult<u32> {
+        let metadata = match self.metadata(denom).await {
+            Ok(metadata) => metadata,
+            Err(e) => return self.fallback_exponents.get(denom).copied().ok_or(e),
+        };
+
+        display_exponent(&metadata).ok_or_else(|| {
+            Error::Custom(format!(
+                "No denom unit matches the display denom {} of {denom}",
+                metadata.display
+            ))
+        })
+    }
+
+    /// Converts a base unit amount to display units, e.g. `1500000uatom` to `1.5`.
+    pub async fn to_display(&self, coin: &Coin) -> CosmosResult<Decimal> {
+        let exponent = self.exponent(&coin.denom).await?;
+        let invalid = || Error::Custom(format!("Invalid amount {}{}", coin.amount, coin.denom));
+        if exponent > MAX_EXPONENT {
+            return Err(invalid());
+        }
+
+        let amount: i128 = coin.amount.parse().map_err(|_| invalid())?;
+        Decimal::try_from_i128_with_scale(amount, exponent).map_err(|_| invalid())
+    }
+
+    /// Converts a display unit amount to a base unit coin, e.g. `1.5` ATOM to
+    /// `1500000uatom`.
+    ///
+    /// # Errors
+    ///
+    /// * Returns `Error::Custom` if the amount is negative or more precise than the base unit.
+    pub async fn from_display(&self, denom: &str, amount: Decimal) -> CosmosResult<Coin> {
+        let exponent = self.exponent(denom).await?;
+        let invalid = || Error::Custom(format!("Invalid amount {amount} for denom {denom}"));
+        if exponent > MAX_EXPONENT || amount.is_sign_negative() {
+            return Err(invalid());
+        }
+
+        let base = amount
+            .checked_mul(Decimal::from_i128_with_scale(10i128.pow(exponent), 0))
+            .ok_or_else(invalid)?;
+        if !base.fract().is_zero() {
+            return Err(invalid());
+        }
+
+        Ok(Coin {
+            denom: denom.to_string(),
+            amount: base.to_u128().ok_or_else(invalid)?.to_string(),
+        })
+    }
+
+    /// Returns the cached metadata of a denom, if any.
+    fn cached(&self, denom: &str) -> Option<Metadata> {
+        self.metadata
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(denom)
+            .cloned()
+    }
+}
@@ -0,0 +1,161 @@
+use cosmrs::proto::cosmos::base::v1beta1::DecCoin;
+use cosmrs::proto::cosmos::distribution::v1beta1::{
+    MsgSetWithdrawAddress, MsgWithdrawDelegatorReward, QueryDelegationRewardsRequest,
+    QueryDelegationRewardsResponse, QueryDelegationTotalRewardsRequest,
+    QueryDelegationTotalRewardsResponse, QueryParamsRequest, QueryParamsResponse,
+    QueryValidatorOutstandingRewardsRequest, QueryValidatorOutstandingRewardsResponse,
+};
+use cosmrs::tx::Body;
+use cosmrs::{AccountId, Any};
+
+use crate::error::{CosmosResult, Error};
+use crate::{client::CosmosClient, rpc::types::Rpc};
+
+/// Main struct providing access to Distribution module functions.
+#[derive(Debug, Clone)]
+pub struct Distribution<T: Rpc + Clone + Send + Sync> {
+    client: CosmosClient<T>,
+}
+
+impl<T: Rpc + Clone + Send + Sync> Distribution<T> {
+    /// Creates a new `Distribution` instance with the provided Cosmos client.
+    pub fn new(client: CosmosClient<T>) -> Self {
+        Self { client }
+    }
+
+    /// Fetches the Distribution module parameters, including the community tax.
+    pub async fn params(&self) -> CosmosResult<QueryParamsResponse> {
+        let query = QueryParamsRequest {};
+        self.client
+            .query("/cosmos.distribution.v1beta1.Query/Params", query)
+            .await
+    }
+
+    /// Fetches the rewards a delegator accrued with a specific validator.
+    pub async fn delegation_rewards(
+        &self,
+        delegator_address: &str,
+        validator_address: &str,
+    ) -> CosmosResult<QueryDelegationRewardsResponse> {
+        let query = QueryDelegationRewardsRequest {
+            delegator_address: delegator_address.to_string(),
+            validator_address: validator_address.to_string(),
+        };
+        self.client
+            .query(
+                "/cosmos.distribution.v1beta1.Query/DelegationRewards",
+                query,
+            )
+            .await
+    }
+
+    /// Fetches the rewards a delegator accrued with each of its validators, and their total.
+    pub async fn delegation_total_rewards(
+        &self,
+        delegator_address: &str,
+    ) -> CosmosResult<QueryDelegationTotalRewardsResponse> {
+        let query = QueryDelegationTotalRewardsRequest {
+            delegator_address: delegator_address.to_string(),
+        };
+        self.client
+            .query(
+                "/cosmos.distribution.v1beta1.Query/DelegationTotalRewards",
+                query,
+            )
+            .await
+    }
+
+    /// Withdraws the rewards of the attached signer from a single validator.
+    ///
+    /// # Errors
+    ///
+    /// * Returns `Error::NoSignerAttached` if no signer is attached to the client.
+    pub async fn withdraw_delegator_reward(
+        &self,
+        validator_address: &str,
+        memo: Option<&str>,
+        timeout_height: Option<u64>,
+    ) -> CosmosResult<Body> {
+        self.withdraw_all_rewards(vec![validator_address.to_string()], memo, timeout_height)
+            .await
+    }
+
+    /// Withdraws the rewards of the attached signer from several validators in a single
+    /// transaction, with one `MsgWithdrawDelegatorReward` per validator.
+    ///
+    /// # Errors
+    ///
+    /// * Returns `Error::NoSignerAttached` if no signer is attached to the client.
+    pub async fn withdraw_all_rewards(
+        &self,
+        validators: Vec<String>,
+        memo: Option<&str>,
+        timeout_height: Option<u64>,
+    ) -> CosmosResult<Body> {
+        let signer = self.client.signer().ok_or(Error::NoSignerAttached)?;
+        let msgs = validators
+            .into_iter()
+            .map(|validator_address| {
+                Any::from_msg(&MsgWithdrawDelegatorReward {
+                    delegator_address: signer.public_address.to_string(),
+                    validator_address,
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        self.client.build_tx(msgs, memo, timeout_height)
+    }
+
+    /// Fetches the outstanding (not yet withdrawn) rewards of a validator, including its
+    /// commission pool.
+    ///
+    /// # Arguments
+    ///
+    /// * `validator_address`: The operator address (`valoper`) of the validator.
+    pub async fn validator_outstanding_rewards(
+        &self,
+        validator_address: &str,
+    ) -> CosmosResult<Vec<DecCoin>> {
+        let query = QueryValidatorOutstandingRewardsRequest {
+            validator_address: validator_address.to_string(),
+        };
+        let resp: QueryValidatorOutstandingRewardsResponse = self
+            .client
+            .query(
+                "/cosmos.distribution.v1beta1.Query/ValidatorOutstandingRewards",
+                query,
+            )
+            .await?;
+
+        Ok(resp.rewards.unwrap_or_default().rewards)
+    }
+
+    /// Sets the address that staking rewards of the attached signer are withdrawn to.
+    ///
+    /// # Errors
+    ///
+    /// * Returns `Error::NoSignerAttached` if no signer is attached to the client.
+    /// * Returns `Error::InvalidAddressPrefix` if `withdraw_address` is not an account address
+    ///   of the signer's chain.
+    pub async fn set_withdraw_address(
+        &self,
+        withdraw_address: &str,
+        memo: Option<&str>,
+        timeout_height: Option<u64>,
+    ) -> CosmosResult<Body> {
+        let signer = self.client.signer().ok_or(Error::NoSignerAttached)?;
+        let expected = signer.public_address.prefix();
+        let address: AccountId = withdraw_address.parse()?;
+        if address.prefix() != expected {
+            return Err(Error::InvalidAddressPrefix {
+                address: withdraw_address.to_string(),
+                expected: expected.to_string(),
+            });
+        }
+
+        let msg = Any::from_msg(&MsgSetWithdrawAddress {
+            delegator_address: signer.public_address.to_string(),
+            withdraw_address: address.to_string(),
+        })?;
+        self.client.build_tx(vec![msg], memo, timeout_height)
+    }
+}
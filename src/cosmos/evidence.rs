@@ -0,0 +1,76 @@
+use cosmrs::proto::cosmos::base::query::v1beta1::{PageRequest, PageResponse};
+use cosmrs::proto::cosmos::evidence::v1beta1::{
+    Equivocation, QueryAllEvidenceRequest, QueryAllEvidenceResponse, QueryEvidenceRequest,
+    QueryEvidenceResponse,
+};
+
+use crate::cosmos::tx::decode_msg;
+use crate::error::CosmosResult;
+use crate::{client::CosmosClient, rpc::types::Rpc};
+
+/// A page of equivocation evidence.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EvidencePage {
+    /// The equivocations of the page. Evidence of other types is skipped.
+    pub evidence: Vec<Equivocation>,
+    /// Pagination of the underlying query, including evidence of other types.
+    pub pagination: Option<PageResponse>,
+}
+
+/// Main struct providing access to Evidence module functions.
+#[derive(Debug, Clone)]
+pub struct Evidence<T: Rpc + Clone + Send + Sync> {
+    client: CosmosClient<T>,
+}
+
+impl<T: Rpc + Clone + Send + Sync> Evidence<T> {
+    /// Creates a new `Evidence` instance with the provided Cosmos client.
+    pub fn new(client: CosmosClient<T>) -> Self {
+        Self { client }
+    }
+
+    /// Fetches a submitted evidence by its hex encoded hash.
+    ///
+    /// # Returns
+    ///
+    /// The equivocation, or `None` if the evidence is of another type.
+    pub async fn evidence(&self, hash: &str) -> CosmosResult<Option<Equivocation>> {
+        #[allow(deprecated)]
+        let query = QueryEvidenceRequest {
+            evidence_hash: vec![],
+            hash: hash.to_string(),
+        };
+        let resp: QueryEvidenceResponse = self
+            .client
+            .query("/cosmos.evidence.v1beta1.Query/Evidence", query)
+            .await?;
+
+        match resp.evidence {
+            Some(evidence) => decode_msg(&evidence),
+            None => Ok(None),
+        }
+    }
+
+    /// Fetches the submitted equivocation evidence with optional pagination.
+    pub async fn all_evidence(
+        &self,
+        pagination: Option<PageRequest>,
+    ) -> CosmosResult<EvidencePage> {
+        let query = QueryAllEvidenceRequest { pagination };
+        let resp: QueryAllEvidenceResponse = self
+            .client
+            .query("/cosmos.evidence.v1beta1.Query/AllEvidence", query)
+            .await?;
+
+        let evidence = resp
+            .evidence
+            .iter()
+            .filter_map(|evidence| decode_msg(evidence).transpose())
+            .collect::<CosmosResult<Vec<_>>>()?;
+
+        Ok(EvidencePage {
+            evidence,
+            pagination: resp.pagination,
+        })
+    }
+}
@@ -0,0 +1,101 @@
+use cosmrs::proto::cosmos::feegrant::v1beta1::{
+    BasicAllowance, MsgGrantAllowance, MsgRevokeAllowance, PeriodicAllowance,
+    QueryAllowanceRequest, QueryAllowanceResponse,
+};
+use cosmrs::tx::Body;
+use cosmrs::Any;
+
+use crate::error::{CosmosResult, Error};
+use crate::{client::CosmosClient, rpc::types::Rpc};
+
+/// A fee allowance a granter extends to a grantee.
+#[derive(Debug, Clone)]
+pub enum FeeAllowance {
+    /// Allows spending up to an optional limit, until an optional expiration.
+    Basic(BasicAllowance),
+    /// Allows spending up to a limit per period, on top of a basic allowance.
+    Periodic(PeriodicAllowance),
+}
+
+impl FeeAllowance {
+    /// Encodes the allowance as the `Any` expected by `MsgGrantAllowance`.
+    pub fn to_any(&self) -> CosmosResult<Any> {
+        let any = match self {
+            FeeAllowance::Basic(allowance) => Any::from_msg(allowance)?,
+            FeeAllowance::Periodic(allowance) => Any::from_msg(allowance)?,
+        };
+
+        Ok(any)
+    }
+}
+
+/// Main struct providing access to Feegrant module functions.
+#[derive(Debug, Clone)]
+pub struct Feegrant<T: Rpc + Clone + Send + Sync> {
+    client: CosmosClient<T>,
+}
+
+impl<T: Rpc + Clone + Send + Sync> Feegrant<T> {
+    /// Creates a new `Feegrant` instance with the provided Cosmos client.
+    pub fn new(client: CosmosClient<T>) -> Self {
+        Self { client }
+    }
+
+    /// Fetches the fee allowance granted by `granter` to `grantee`.
+    pub async fn allowance(
+        &self,
+        granter: &str,
+        grantee: &str,
+    ) -> CosmosResult<QueryAllowanceResponse> {
+        let query = QueryAllowanceRequest {
+            granter: granter.to_string(),
+            grantee: grantee.to_string(),
+        };
+        self.client
+            .query("/cosmos.feegrant.v1beta1.Query/Allowance", query)
+            .await
+    }
+
+    /// Grants `grantee` an allowance to pay its transaction fees from the attached signer's
+    /// account.
+    ///
+    /// # Errors
+    ///
+    /// * Returns `Error::NoSignerAttached` if no signer is attached to the client.
+    pub async fn grant_allowance(
+        &self,
+        grantee: &str,
+        allowance: FeeAllowance,
+        memo: Option<&str>,
+        timeout_height: Option<u64>,
+    ) -> CosmosResult<Body> {
+        let signer = self.client.signer().ok_or(Error::NoSignerAttached)?;
+        let msg = Any::from_msg(&MsgGrantAllowance {
+            granter: signer.public_address.to_string(),
+            grantee: grantee.to_string(),
+            allowance: Some(allowance.to_any()?),
+        })?;
+
+        self.client.build_tx(vec![msg], memo, timeout_height)
+    }
+
+    /// Revokes the fee allowance the attached signer granted to `grantee`.
+    ///
+    /// # Errors
+    ///
+    /// * Returns `Error::NoSignerAttached` if no signer is attached to the client.
+    pub async fn revoke_allowance(
+        &self,
+        grantee: &str,
+        memo: Option<&str>,
+        timeout_height: Option<u64>,
+    ) -> CosmosResult<Body> {
+        let signer = self.client.signer().ok_or(Error::NoSignerAttached)?;
+        let msg = Any::from_msg(&MsgRevokeAllowance {
+            granter: signer.public_address.to_string(),
+            grantee: grantee.to_string(),
+        })?;
+
+        self.client.build_tx(vec![msg], memo, timeout_height)
+    }
+}
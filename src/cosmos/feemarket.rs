@@ -0,0 +1,139 @@
+use cosmrs::proto::cosmos::base::v1beta1::{Coin, DecCoin};
+
+use crate::decimal::{parse_dec, DEC_ONE};
+use crate::error::{CosmosResult, Error};
+use crate::{client::CosmosClient, rpc::types::Rpc};
+
+/// Request type for `/feemarket.feemarket.v1.Query/GasPrice`.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct GasPriceRequest {
+    #[prost(string, tag = "1")]
+    pub denom: String,
+}
+
+/// Response type for `/feemarket.feemarket.v1.Query/GasPrice`.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct GasPriceResponse {
+    #[prost(message, optional, tag = "1")]
+    pub price: Option<DecCoin>,
+}
+
+/// Request type for `/feemarket.feemarket.v1.Query/GasPrices`.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct GasPricesRequest {}
+
+/// Response type for `/feemarket.feemarket.v1.Query/GasPrices`.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct GasPricesResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub prices: Vec<DecCoin>,
+}
+
+/// Request type for `/osmosis.txfees.v1beta1.Query/GetEipBaseFee`.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct QueryEipBaseFeeRequest {}
+
+/// Response type for `/osmosis.txfees.v1beta1.Query/GetEipBaseFee`.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct QueryEipBaseFeeResponse {
+    /// The base fee per unit of gas, in the base denom, as a `LegacyDec`.
+    #[prost(string, tag = "1")]
+    pub base_fee: String,
+}
+
+/// Request type for `/osmosis.txfees.v1beta1.Query/BaseDenom`.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct QueryBaseDenomRequest {}
+
+/// Response type for `/osmosis.txfees.v1beta1.Query/BaseDenom`.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct QueryBaseDenomResponse {
+    #[prost(string, tag = "1")]
+    pub base_denom: String,
+}
+
+/// Main struct providing access to dynamic gas prices, from either the feemarket module or
+/// Osmosis' txfees module.
+#[derive(Debug, Clone)]
+pub struct FeeMarket<T: Rpc + Clone + Send + Sync> {
+    client: CosmosClient<T>,
+}
+
+impl<T: Rpc + Clone + Send + Sync> FeeMarket<T> {
+    /// Creates a new `FeeMarket` instance with the provided Cosmos client.
+    pub fn new(client: CosmosClient<T>) -> Self {
+        Self { client }
+    }
+
+    /// Fetches the current feemarket gas price in the given denom.
+    pub async fn gas_price(&self, denom: &str) -> CosmosResult<GasPriceResponse> {
+        let query = GasPriceRequest {
+            denom: denom.to_string(),
+        };
+        self.client
+            .query("/feemarket.feemarket.v1.Query/GasPrice", query)
+            .await
+    }
+
+    /// Fetches the current feemarket gas price in every accepted fee denom.
+    pub async fn gas_prices(&self) -> CosmosResult<GasPricesResponse> {
+        let query = GasPricesRequest {};
+        self.client
+            .query("/feemarket.feemarket.v1.Query/GasPrices", query)
+            .await
+    }
+
+    /// Fetches Osmosis' current EIP-1559 style base fee.
+    pub async fn eip_base_fee(&self) -> CosmosResult<QueryEipBaseFeeResponse> {
+        let query = QueryEipBaseFeeRequest {};
+        self.client
+            .query("/osmosis.txfees.v1beta1.Query/GetEipBaseFee", query)
+            .await
+    }
+
+    /// Fetches the denom Osmosis' base fee is expressed in.
+    pub async fn base_denom(&self) -> CosmosResult<QueryBaseDenomResponse> {
+        let query = QueryBaseDenomRequest {};
+        self.client
+            .query("/osmosis.txfees.v1beta1.Query/BaseDenom", query)
+            .await
+    }
+
+    /// Selects the gas price to pay fees with.
+    ///
+    /// The feemarket gas prices are used if the chain runs the module, otherwise Osmosis' base
+    /// fee. The first denom of `preferred_denoms` with a price is selected, falling back to
+    /// the first available price.
+    pub async fn select_gas_price(&self, preferred_denoms: &[String]) -> CosmosResult<DecCoin> {
+        let prices = match self.gas_prices().await {
+            Ok(resp) => resp.prices,
+            Err(_) => vec![DecCoin {
+                denom: self.base_denom().await?.base_denom,
+                amount: self.eip_base_fee().await?.base_fee,
+            }],
+        };
+
+        preferred_denoms
+            .iter()
+            .find_map(|denom| prices.iter().find(|price| &price.denom == denom))
+            .or_else(|| prices.first())
+            .cloned()
+            .ok_or_else(|| Error::Custom("No gas price available".to_string()))
+    }
+
+    /// Computes the fee of a transaction with the given gas limit at the selected gas price,
+    /// rounded up.
+    pub async fn fee_for_gas(
+        &self,
+        gas_limit: u64,
+        preferred_denoms: &[String],
+    ) -> CosmosResult<Coin> {
+        let price = self.select_gas_price(preferred_denoms).await?;
+        let fee = parse_dec(&price.amount)? * u128::from(gas_limit);
+
+        Ok(Coin {
+            denom: price.denom,
+            amount: fee.div_ceil(DEC_ONE).to_string(),
+        })
+    }
+}
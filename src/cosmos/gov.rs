@@ -0,0 +1,505 @@
+use cosmrs::proto::cosmos::base::query::v1beta1::PageRequest;
+use cosmrs::proto::cosmos::base::v1beta1::Coin;
+use cosmrs::proto::cosmos::gov::v1::{
+    MsgSubmitProposal, QueryDepositsRequest, QueryDepositsResponse, QueryParamsRequest,
+    QueryParamsResponse,
+};
+use cosmrs::proto::cosmos::gov::v1beta1::{MsgDeposit, MsgVote};
+use cosmrs::proto::cosmos::{bank, distribution, gov, mint, staking};
+use cosmrs::tx::Body;
+use cosmrs::{AccountId, Any};
+use rust_decimal::Decimal;
+use sha2::{Digest, Sha256};
+
+use crate::cosmos::staking::Staking;
+use crate::decimal::{parse_dec, to_decimal, DEC_PRECISION};
+use crate::error::{CosmosResult, Error};
+use crate::{client::CosmosClient, rpc::types::Rpc};
+
+/// A full replacement of a module's parameters, submitted as a `MsgUpdateParams` through a
+/// gov v1 proposal (cosmos-sdk 0.47 and later).
+#[derive(Debug, Clone)]
+pub enum ParamsUpdate {
+    Bank(bank::v1beta1::Params),
+    Staking(staking::v1beta1::Params),
+    Distribution(distribution::v1beta1::Params),
+    Gov(gov::v1::Params),
+    Mint(mint::v1beta1::Params),
+}
+
+impl ParamsUpdate {
+    /// Encodes the update as the module's `MsgUpdateParams` executed by `authority`.
+    pub fn to_any(&self, authority: &str) -> CosmosResult<Any> {
+        let authority = authority.to_string();
+        let any = match self {
+            ParamsUpdate::Bank(params) => Any::from_msg(&bank::v1beta1::MsgUpdateParams {
+                authority,
+                params: Some(params.clone()),
+            })?,
+            ParamsUpdate::Staking(params) => Any::from_msg(&staking::v1beta1::MsgUpdateParams {
+                authority,
+                params: Some(params.clone()),
+            })?,
+            ParamsUpdate::Distribution(params) => {
+                Any::from_msg(&distribution::v1beta1::MsgUpdateParams {
+                    authority,
+                    params: Some(params.clone()),
+                })?
+            }
+            ParamsUpdate::Gov(params) => Any::from_msg(&gov::v1::MsgUpdateParams {
+                authority,
+                params: Some(params.clone()),
+            })?,
+            ParamsUpdate::Mint(params) => Any::from_msg(&mint::v1beta1::MsgUpdateParams {
+                authority,
+                params: Some(params.clone()),
+            })?,
+        };
+
+        Ok(any)
+    }
+}
+
+/// Version of the gov query service to use.
+///
+/// Chains running cosmos-sdk 0.46 and later serve both; older chains only serve `V1Beta1`,
+/// and proposals created through `MsgExecLegacyContent` only decode fully with `V1Beta1`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GovVersion {
+    V1,
+    V1Beta1,
+}
+
+/// Status of a proposal, used to filter the `proposals` query.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProposalStatus {
+    DepositPeriod,
+    VotingPeriod,
+    Passed,
+    Rejected,
+    Failed,
+}
+
+impl From<ProposalStatus> for i32 {
+    fn from(status: ProposalStatus) -> Self {
+        match status {
+            ProposalStatus::DepositPeriod => 1,
+            ProposalStatus::VotingPeriod => 2,
+            ProposalStatus::Passed => 3,
+            ProposalStatus::Rejected => 4,
+            ProposalStatus::Failed => 5,
+        }
+    }
+}
+
+/// Option of a vote on a proposal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VoteOption {
+    Yes,
+    Abstain,
+    No,
+    NoWithVeto,
+}
+
+impl From<VoteOption> for i32 {
+    fn from(option: VoteOption) -> Self {
+        match option {
+            VoteOption::Yes => 1,
+            VoteOption::Abstain => 2,
+            VoteOption::No => 3,
+            VoteOption::NoWithVeto => 4,
+        }
+    }
+}
+
+/// Response of the `proposals` query in the requested gov version.
+#[derive(Debug, Clone)]
+pub enum ProposalsResponse {
+    V1(gov::v1::QueryProposalsResponse),
+    V1Beta1(gov::v1beta1::QueryProposalsResponse),
+}
+
+/// Response of the `proposal` query in the requested gov version.
+#[derive(Debug, Clone)]
+pub enum ProposalResponse {
+    V1(gov::v1::QueryProposalResponse),
+    V1Beta1(gov::v1beta1::QueryProposalResponse),
+}
+
+/// Response of the `tally_result` query in the requested gov version.
+#[derive(Debug, Clone)]
+pub enum TallyResultResponse {
+    V1(gov::v1::QueryTallyResultResponse),
+    V1Beta1(gov::v1beta1::QueryTallyResultResponse),
+}
+
+/// Response of the `votes` query in the requested gov version.
+#[derive(Debug, Clone)]
+pub enum VotesResponse {
+    V1(gov::v1::QueryVotesResponse),
+    V1Beta1(gov::v1beta1::QueryVotesResponse),
+}
+
+/// Tally of a proposal weighed against the bonded tokens and the gov tallying parameters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TallySummary {
+    /// Total bonded tokens, the denominator of the turnout.
+    pub bonded_tokens: u128,
+    /// Share of the bonded tokens that voted.
+    pub turnout: Decimal,
+    /// Share of the non-abstaining votes that voted yes.
+    pub yes_ratio: Decimal,
+    /// Share of the non-abstaining votes that voted no.
+    pub no_ratio: Decimal,
+    /// Share of all votes that voted no with veto.
+    pub veto_ratio: Decimal,
+    /// Share of all votes that abstained.
+    pub abstain_ratio: Decimal,
+    /// Whether the turnout reaches the quorum.
+    pub quorum_met: bool,
+    /// Whether the no with veto votes exceed the veto threshold.
+    pub vetoed: bool,
+    /// Whether the proposal would pass if voting ended now.
+    pub passes: bool,
+}
+
+/// Derives the address of the gov module account, the default authority of
+/// `MsgUpdateParams`, for a given bech32 account prefix.
+pub fn gov_module_address(prefix: &str) -> CosmosResult<AccountId> {
+    let hash = Sha256::digest(b"gov");
+    Ok(AccountId::new(prefix, &hash[..20])?)
+}
+
+/// Main struct providing access to Gov module functions.
+#[derive(Debug, Clone)]
+pub struct Gov<T: Rpc + Clone + Send + Sync> {
+    client: CosmosClient<T>,
+}
+
+impl<T: Rpc + Clone + Send + Sync> Gov<T> {
+    /// Creates a new `Gov` instance with the provided Cosmos client.
+    pub fn new(client: CosmosClient<T>) -> Self {
+        Self { client }
+    }
+
+    /// Fetches the gov module parameters of the given type (`"voting"`, `"deposit"` or
+    /// `"tallying"`).
+    pub async fn params(&self, params_type: &str) -> CosmosResult<QueryParamsResponse> {
+        let query = QueryParamsRequest {
+            params_type: params_type.to_string(),
+        };
+        self.client
+            .query("/cosmos.gov.v1.Query/Params", query)
+            .await
+    }
+
+    /// Fetches the minimum deposit a proposal needs to enter its voting period.
+    pub async fn min_deposit(&self) -> CosmosResult<Vec<Coin>> {
+        let resp = self.params("deposit").await?;
+        if let Some(params) = resp.params {
+            return Ok(params.min_deposit);
+        }
+
+        #[allow(deprecated)]
+        let min_deposit = resp.deposit_params.unwrap_or_default().min_deposit;
+        Ok(min_deposit)
+    }
+
+    /// Fetches proposals, optionally filtered by status.
+    ///
+    /// # Arguments
+    ///
+    /// * `status`: Only return proposals with this status, or all proposals if `None`.
+    /// * `pagination`: Optional pagination of the results.
+    /// * `version`: The gov query service to use.
+    pub async fn proposals(
+        &self,
+        status: Option<ProposalStatus>,
+        pagination: Option<PageRequest>,
+        version: GovVersion,
+    ) -> CosmosResult<ProposalsResponse> {
+        let proposal_status = status.map(i32::from).unwrap_or_default();
+        match version {
+            GovVersion::V1 => {
+                let query = gov::v1::QueryProposalsRequest {
+                    proposal_status,
+                    pagination,
+                    ..Default::default()
+                };
+                self.client
+                    .query("/cosmos.gov.v1.Query/Proposals", query)
+                    .await
+                    .map(ProposalsResponse::V1)
+            }
+            GovVersion::V1Beta1 => {
+                let query = gov::v1beta1::QueryProposalsRequest {
+                    proposal_status,
+                    pagination,
+                    ..Default::default()
+                };
+                self.client
+                    .query("/cosmos.gov.v1beta1.Query/Proposals", query)
+                    .await
+                    .map(ProposalsResponse::V1Beta1)
+            }
+        }
+    }
+
+    /// Fetches a single proposal by its ID.
+    pub async fn proposal(
+        &self,
+        proposal_id: u64,
+        version: GovVersion,
+    ) -> CosmosResult<ProposalResponse> {
+        match version {
+            GovVersion::V1 => {
+                let query = gov::v1::QueryProposalRequest { proposal_id };
+                self.client
+                    .query("/cosmos.gov.v1.Query/Proposal", query)
+                    .await
+                    .map(ProposalResponse::V1)
+            }
+            GovVersion::V1Beta1 => {
+                let query = gov::v1beta1::QueryProposalRequest { proposal_id };
+                self.client
+                    .query("/cosmos.gov.v1beta1.Query/Proposal", query)
+                    .await
+                    .map(ProposalResponse::V1Beta1)
+            }
+        }
+    }
+
+    /// Fetches the current tally of a proposal. Once voting ends, this is the final tally.
+    pub async fn tally_result(
+        &self,
+        proposal_id: u64,
+        version: GovVersion,
+    ) -> CosmosResult<TallyResultResponse> {
+        match version {
+            GovVersion::V1 => {
+                let query = gov::v1::QueryTallyResultRequest { proposal_id };
+                self.client
+                    .query("/cosmos.gov.v1.Query/TallyResult", query)
+                    .await
+                    .map(TallyResultResponse::V1)
+            }
+            GovVersion::V1Beta1 => {
+                let query = gov::v1beta1::QueryTallyResultRequest { proposal_id };
+                self.client
+                    .query("/cosmos.gov.v1beta1.Query/TallyResult", query)
+                    .await
+                    .map(TallyResultResponse::V1Beta1)
+            }
+        }
+    }
+
+    /// Computes whether a proposal reaches quorum and passes, following the SDK tallying rules,
+    /// from its current tally, the bonded tokens and the gov tallying parameters.
+    ///
+    /// Only chains serving gov v1 are supported.
+    pub async fn tally_with_quorum(&self, proposal_id: u64) -> CosmosResult<TallySummary> {
+        let query = gov::v1::QueryTallyResultRequest { proposal_id };
+        let tally = self
+            .client
+            .query::<_, gov::v1::QueryTallyResultResponse>(
+                "/cosmos.gov.v1.Query/TallyResult",
+                query,
+            )
+            .await?
+            .tally
+            .unwrap_or_default();
+        let params = self
+            .params("tallying")
+            .await?
+            .params
+            .ok_or_else(|| Error::Custom("No gov params".to_string()))?;
+        let bonded_tokens = Staking::new(self.client.clone())
+            .pool()
+            .await?
+            .pool
+            .unwrap_or_default()
+            .bonded_tokens;
+
+        let parse_int = |value: &str| -> CosmosResult<u128> {
+            if value.is_empty() {
+                return Ok(0);
+            }
+            value
+                .parse()
+                .map_err(|e| Error::wrap(format!("Invalid token amount {value}"), e))
+        };
+        let parse_ratio = |value: &str| to_decimal(parse_dec(value)?, DEC_PRECISION as u32);
+        let ratio = |part: u128, total: u128| -> CosmosResult<Decimal> {
+            if total == 0 {
+                return Ok(Decimal::ZERO);
+            }
+            Ok(to_decimal(part, 0)? / to_decimal(total, 0)?)
+        };
+
+        let yes = parse_int(&tally.yes_count)?;
+        let abstain = parse_int(&tally.abstain_count)?;
+        let no = parse_int(&tally.no_count)?;
+        let no_with_veto = parse_int(&tally.no_with_veto_count)?;
+        let bonded_tokens = parse_int(&bonded_tokens)?;
+        let voted = yes + abstain + no + no_with_veto;
+        let non_abstaining = voted - abstain;
+
+        let turnout = ratio(voted, bonded_tokens)?;
+        let yes_ratio = ratio(yes, non_abstaining)?;
+        let veto_ratio = ratio(no_with_veto, voted)?;
+        let quorum_met = voted > 0 && turnout >= parse_ratio(&params.quorum)?;
+        let vetoed = quorum_met && veto_ratio > parse_ratio(&params.veto_threshold)?;
+        let passes = quorum_met
+            && !vetoed
+            && non_abstaining > 0
+            && yes_ratio > parse_ratio(&params.threshold)?;
+
+        Ok(TallySummary {
+            bonded_tokens,
+            turnout,
+            yes_ratio,
+            no_ratio: ratio(no + no_with_veto, non_abstaining)?,
+            veto_ratio,
+            abstain_ratio: ratio(abstain, voted)?,
+            quorum_met,
+            vetoed,
+            passes,
+        })
+    }
+
+    /// Fetches the deposits made so far on a proposal.
+    pub async fn proposal_deposits(
+        &self,
+        proposal_id: u64,
+        pagination: Option<PageRequest>,
+    ) -> CosmosResult<QueryDepositsResponse> {
+        let query = QueryDepositsRequest {
+            proposal_id,
+            pagination,
+        };
+        self.client
+            .query("/cosmos.gov.v1.Query/Deposits", query)
+            .await
+    }
+
+    /// Fetches all votes cast on a proposal, including their weighted options.
+    ///
+    /// # Arguments
+    ///
+    /// * `proposal_id`: The ID of the proposal.
+    /// * `pagination`: Optional pagination of the results.
+    /// * `version`: The gov query service to use.
+    pub async fn votes(
+        &self,
+        proposal_id: u64,
+        pagination: Option<PageRequest>,
+        version: GovVersion,
+    ) -> CosmosResult<VotesResponse> {
+        match version {
+            GovVersion::V1 => {
+                let query = gov::v1::QueryVotesRequest {
+                    proposal_id,
+                    pagination,
+                };
+                self.client
+                    .query("/cosmos.gov.v1.Query/Votes", query)
+                    .await
+                    .map(VotesResponse::V1)
+            }
+            GovVersion::V1Beta1 => {
+                let query = gov::v1beta1::QueryVotesRequest {
+                    proposal_id,
+                    pagination,
+                };
+                self.client
+                    .query("/cosmos.gov.v1beta1.Query/Votes", query)
+                    .await
+                    .map(VotesResponse::V1Beta1)
+            }
+        }
+    }
+
+    /// Votes on a proposal with the attached signer.
+    ///
+    /// # Errors
+    ///
+    /// * Returns `Error::NoSignerAttached` if no signer is attached to the client.
+    pub async fn vote(
+        &self,
+        proposal_id: u64,
+        option: VoteOption,
+        memo: Option<&str>,
+        timeout_height: Option<u64>,
+    ) -> CosmosResult<Body> {
+        let signer = self.client.signer().ok_or(Error::NoSignerAttached)?;
+        let msg = Any::from_msg(&MsgVote {
+            proposal_id,
+            voter: signer.public_address.to_string(),
+            option: option.into(),
+        })?;
+
+        self.client.build_tx(vec![msg], memo, timeout_height)
+    }
+
+    /// Adds a deposit to a proposal from the attached signer.
+    ///
+    /// # Errors
+    ///
+    /// * Returns `Error::NoSignerAttached` if no signer is attached to the client.
+    pub async fn deposit(
+        &self,
+        proposal_id: u64,
+        amount: Vec<Coin>,
+        memo: Option<&str>,
+        timeout_height: Option<u64>,
+    ) -> CosmosResult<Body> {
+        let signer = self.client.signer().ok_or(Error::NoSignerAttached)?;
+        let msg = Any::from_msg(&MsgDeposit {
+            proposal_id,
+            depositor: signer.public_address.to_string(),
+            amount,
+        })?;
+
+        self.client.build_tx(vec![msg], memo, timeout_height)
+    }
+
+    /// Builds a gov v1 proposal updating the parameters of one or more modules.
+    ///
+    /// # Arguments
+    ///
+    /// * `updates`: The new parameters. Each update replaces all parameters of its module, so
+    ///   start from the current values returned by the module's `params` query.
+    /// * `initial_deposit`: The deposit sent along with the proposal.
+    /// * `title`: The proposal title.
+    /// * `summary`: The proposal summary.
+    /// * `memo`: An optional memo to attach to the transaction.
+    /// * `timeout_height`: The height after which the transaction can no longer be included. If
+    ///   `None`, the client's timeout height offset applies at signing time, if configured.
+    pub async fn submit_params_update(
+        &self,
+        updates: Vec<ParamsUpdate>,
+        initial_deposit: Vec<Coin>,
+        title: &str,
+        summary: &str,
+        memo: Option<&str>,
+        timeout_height: Option<u64>,
+    ) -> CosmosResult<Body> {
+        let signer = self.client.signer().ok_or(Error::NoSignerAttached)?;
+        let authority = gov_module_address(signer.public_address.prefix())?.to_string();
+        let messages = updates
+            .iter()
+            .map(|update| update.to_any(&authority))
+            .collect::<CosmosResult<Vec<_>>>()?;
+
+        let msg = Any::from_msg(&MsgSubmitProposal {
+            messages,
+            initial_deposit,
+            proposer: signer.public_address.to_string(),
+            title: title.to_string(),
+            summary: summary.to_string(),
+            ..Default::default()
+        })?;
+
+        self.client.build_tx(vec![msg], memo, timeout_height)
+    }
+}
@@ -0,0 +1,616 @@
+use std::time::Duration;
+
+use cosmrs::proto::cosmos::base::query::v1beta1::{PageRequest, PageResponse};
+use cosmrs::proto::tendermint::google::protobuf::{Duration as ProtoDuration, Timestamp};
+use cosmrs::tx::Body;
+use cosmrs::Any;
+
+use crate::cosmos::gov::VoteOption;
+use crate::error::{CosmosResult, Error};
+use crate::{client::CosmosClient, rpc::types::Rpc};
+
+/// `cosmos.group.v1.GroupInfo`: a group and its admin.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct GroupInfo {
+    /// The unique id of the group.
+    #[prost(uint64, tag = "1")]
+    pub id: u64,
+    /// The account allowed to update the group.
+    #[prost(string, tag = "2")]
+    pub admin: String,
+    /// Arbitrary metadata attached to the group.
+    #[prost(string, tag = "3")]
+    pub metadata: String,
+    /// Incremented on every update of the group or its members.
+    #[prost(uint64, tag = "4")]
+    pub version: u64,
+    /// The sum of the member weights, as a decimal string.
+    #[prost(string, tag = "5")]
+    pub total_weight: String,
+    /// When the group was created.
+    #[prost(message, optional, tag = "6")]
+    pub created_at: Option<Timestamp>,
+}
+
+/// `cosmos.group.v1.Member`: a member of a group and its voting weight.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Member {
+    #[prost(string, tag = "1")]
+    pub address: String,
+    /// The voting weight, as a decimal string.
+    #[prost(string, tag = "2")]
+    pub weight: String,
+    #[prost(string, tag = "3")]
+    pub metadata: String,
+    #[prost(message, optional, tag = "4")]
+    pub added_at: Option<Timestamp>,
+}
+
+/// `cosmos.group.v1.MemberRequest`: a member to add to a group.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MemberRequest {
+    #[prost(string, tag = "1")]
+    pub address: String,
+    /// The voting weight, as a decimal string. A weight of `"0"` removes the member.
+    #[prost(string, tag = "2")]
+    pub weight: String,
+    #[prost(string, tag = "3")]
+    pub metadata: String,
+}
+
+/// `cosmos.group.v1.GroupMember`.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct GroupMember {
+    #[prost(uint64, tag = "1")]
+    pub group_id: u64,
+    #[prost(message, optional, tag = "2")]
+    pub member: Option<Member>,
+}
+
+/// `cosmos.group.v1.TallyResult`: the vote weights of a proposal, as decimal strings.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct TallyResult {
+    #[prost(string, tag = "1")]
+    pub yes_count: String,
+    #[prost(string, tag = "2")]
+    pub abstain_count: String,
+    #[prost(string, tag = "3")]
+    pub no_count: String,
+    #[prost(string, tag = "4")]
+    pub no_with_veto_count: String,
+}
+
+/// `cosmos.group.v1.Proposal`: a proposal of a group policy.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Proposal {
+    #[prost(uint64, tag = "1")]
+    pub id: u64,
+    #[prost(string, tag = "2")]
+    pub group_policy_address: String,
+    #[prost(string, tag = "3")]
+    pub metadata: String,
+    #[prost(string, repeated, tag = "4")]
+    pub proposers: Vec<String>,
+    #[prost(message, optional, tag = "5")]
+    pub submit_time: Option<Timestamp>,
+    #[prost(uint64, tag = "6")]
+    pub group_version: u64,
+    #[prost(uint64, tag = "7")]
+    pub group_policy_version: u64,
+    /// The `cosmos.group.v1.ProposalStatus` of the proposal.
+    #[prost(int32, tag = "8")]
+    pub status: i32,
+    /// The tally, only populated once voting has ended.
+    #[prost(message, optional, tag = "9")]
+    pub final_tally_result: Option<TallyResult>,
+    #[prost(message, optional, tag = "10")]
+    pub voting_period_end: Option<Timestamp>,
+    /// The `cosmos.group.v1.ProposalExecutorResult` of the proposal.
+    #[prost(int32, tag = "11")]
+    pub executor_result: i32,
+    #[prost(message, repeated, tag = "12")]
+    pub messages: Vec<Any>,
+    #[prost(string, tag = "13")]
+    pub title: String,
+    #[prost(string, tag = "14")]
+    pub summary: String,
+}
+
+/// `cosmos.group.v1.QueryGroupInfoRequest`.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct QueryGroupInfoRequest {
+    #[prost(uint64, tag = "1")]
+    pub group_id: u64,
+}
+
+/// `cosmos.group.v1.QueryGroupInfoResponse`.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct QueryGroupInfoResponse {
+    #[prost(message, optional, tag = "1")]
+    pub info: Option<GroupInfo>,
+}
+
+/// `cosmos.group.v1.QueryGroupMembersRequest`.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct QueryGroupMembersRequest {
+    #[prost(uint64, tag = "1")]
+    pub group_id: u64,
+    #[prost(message, optional, tag = "2")]
+    pub pagination: Option<PageRequest>,
+}
+
+/// `cosmos.group.v1.QueryGroupMembersResponse`.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct QueryGroupMembersResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub members: Vec<GroupMember>,
+    #[prost(message, optional, tag = "2")]
+    pub pagination: Option<PageResponse>,
+}
+
+/// `cosmos.group.v1.QueryProposalsByGroupPolicyRequest`.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct QueryProposalsByGroupPolicyRequest {
+    #[prost(string, tag = "1")]
+    pub address: String,
+    #[prost(message, optional, tag = "2")]
+    pub pagination: Option<PageRequest>,
+}
+
+/// `cosmos.group.v1.QueryProposalsByGroupPolicyResponse`.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct QueryProposalsByGroupPolicyResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub proposals: Vec<Proposal>,
+    #[prost(message, optional, tag = "2")]
+    pub pagination: Option<PageResponse>,
+}
+
+/// `cosmos.group.v1.GroupPolicyInfo`: a group policy account and its decision policy.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct GroupPolicyInfo {
+    /// The account address of the group policy, which holds its funds.
+    #[prost(string, tag = "1")]
+    pub address: String,
+    /// The id of the group the policy belongs to.
+    #[prost(uint64, tag = "2")]
+    pub group_id: u64,
+    /// The account allowed to update the policy.
+    #[prost(string, tag = "3")]
+    pub admin: String,
+    /// Arbitrary metadata attached to the policy.
+    #[prost(string, tag = "4")]
+    pub metadata: String,
+    /// Incremented on every update of the policy.
+    #[prost(uint64, tag = "5")]
+    pub version: u64,
+    /// The decision policy, a `ThresholdDecisionPolicy` or `PercentageDecisionPolicy`.
+    #[prost(message, optional, tag = "6")]
+    pub decision_policy: Option<Any>,
+    /// When the policy was created.
+    #[prost(message, optional, tag = "7")]
+    pub created_at: Option<Timestamp>,
+}
+
+/// `cosmos.group.v1.QueryGroupPoliciesByGroupRequest`.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct QueryGroupPoliciesByGroupRequest {
+    #[prost(uint64, tag = "1")]
+    pub group_id: u64,
+    #[prost(message, optional, tag = "2")]
+    pub pagination: Option<PageRequest>,
+}
+
+/// `cosmos.group.v1.QueryGroupPoliciesByGroupResponse`.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct QueryGroupPoliciesByGroupResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub group_policies: Vec<GroupPolicyInfo>,
+    #[prost(message, optional, tag = "2")]
+    pub pagination: Option<PageResponse>,
+}
+
+/// `cosmos.group.v1.MsgCreateGroupPolicy`.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MsgCreateGroupPolicy {
+    #[prost(string, tag = "1")]
+    pub admin: String,
+    #[prost(uint64, tag = "2")]
+    pub group_id: u64,
+    #[prost(string, tag = "3")]
+    pub metadata: String,
+    #[prost(message, optional, tag = "4")]
+    pub decision_policy: Option<Any>,
+}
+
+impl prost::Name for MsgCreateGroupPolicy {
+    const NAME: &'static str = "MsgCreateGroupPolicy";
+    const PACKAGE: &'static str = "cosmos.group.v1";
+}
+
+/// `cosmos.group.v1.MsgCreateGroup`.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MsgCreateGroup {
+    #[prost(string, tag = "1")]
+    pub admin: String,
+    #[prost(message, repeated, tag = "2")]
+    pub members: Vec<MemberRequest>,
+    #[prost(string, tag = "3")]
+    pub metadata: String,
+}
+
+impl prost::Name for MsgCreateGroup {
+    const NAME: &'static str = "MsgCreateGroup";
+    const PACKAGE: &'static str = "cosmos.group.v1";
+}
+
+/// `cosmos.group.v1.MsgSubmitProposal`.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MsgSubmitProposal {
+    #[prost(string, tag = "1")]
+    pub group_policy_address: String,
+    #[prost(string, repeated, tag = "2")]
+    pub proposers: Vec<String>,
+    #[prost(string, tag = "3")]
+    pub metadata: String,
+    #[prost(message, repeated, tag = "4")]
+    pub messages: Vec<Any>,
+    #[prost(int32, tag = "5")]
+    pub exec: i32,
+    #[prost(string, tag = "6")]
+    pub title: String,
+    #[prost(string, tag = "7")]
+    pub summary: String,
+}
+
+impl prost::Name for MsgSubmitProposal {
+    const NAME: &'static str = "MsgSubmitProposal";
+    const PACKAGE: &'static str = "cosmos.group.v1";
+}
+
+/// `cosmos.group.v1.MsgVote`.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MsgVote {
+    #[prost(uint64, tag = "1")]
+    pub proposal_id: u64,
+    #[prost(string, tag = "2")]
+    pub voter: String,
+    #[prost(int32, tag = "3")]
+    pub option: i32,
+    #[prost(string, tag = "4")]
+    pub metadata: String,
+    #[prost(int32, tag = "5")]
+    pub exec: i32,
+}
+
+impl prost::Name for MsgVote {
+    const NAME: &'static str = "MsgVote";
+    const PACKAGE: &'static str = "cosmos.group.v1";
+}
+
+/// `cosmos.group.v1.MsgExec`.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MsgExec {
+    #[prost(uint64, tag = "1")]
+    pub proposal_id: u64,
+    #[prost(string, tag = "2")]
+    pub executor: String,
+}
+
+impl prost::Name for MsgExec {
+    const NAME: &'static str = "MsgExec";
+    const PACKAGE: &'static str = "cosmos.group.v1";
+}
+
+/// `cosmos.group.v1.DecisionPolicyWindows`.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct DecisionPolicyWindows {
+    #[prost(message, optional, tag = "1")]
+    pub voting_period: Option<ProtoDuration>,
+    #[prost(message, optional, tag = "2")]
+    pub min_execution_period: Option<ProtoDuration>,
+}
+
+/// `cosmos.group.v1.ThresholdDecisionPolicy`.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ThresholdDecisionPolicy {
+    #[prost(string, tag = "1")]
+    pub threshold: String,
+    #[prost(message, optional, tag = "2")]
+    pub windows: Option<DecisionPolicyWindows>,
+}
+
+impl prost::Name for ThresholdDecisionPolicy {
+    const NAME: &'static str = "ThresholdDecisionPolicy";
+    const PACKAGE: &'static str = "cosmos.group.v1";
+}
+
+/// `cosmos.group.v1.PercentageDecisionPolicy`.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct PercentageDecisionPolicy {
+    #[prost(string, tag = "1")]
+    pub percentage: String,
+    #[prost(message, optional, tag = "2")]
+    pub windows: Option<DecisionPolicyWindows>,
+}
+
+impl prost::Name for PercentageDecisionPolicy {
+    const NAME: &'static str = "PercentageDecisionPolicy";
+    const PACKAGE: &'static str = "cosmos.group.v1";
+}
+
+/// Rules deciding whether a group proposal passes.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DecisionPolicy {
+    /// Passes once the sum of yes-vote weights reaches `threshold`.
+    Threshold {
+        threshold: String,
+        voting_period: Duration,
+        min_execution_period: Duration,
+    },
+    /// Passes once yes votes reach `percentage` (e.g. `"0.5"`) of the total group weight.
+    Percentage {
+        percentage: String,
+        voting_period: Duration,
+        min_execution_period: Duration,
+    },
+}
+
+impl DecisionPolicy {
+    /// Encodes the policy as the `Any` expected by group messages.
+    pub fn to_any(&self) -> CosmosResult<Any> {
+        let windows = |voting_period: &Duration, min_execution_period: &Duration| {
+            Some(DecisionPolicyWindows {
+                voting_period: Some(to_proto_duration(voting_period)),
+                min_execution_period: Some(to_proto_duration(min_execution_period)),
+            })
+        };
+
+        let any = match self {
+            DecisionPolicy::Threshold {
+                threshold,
+                voting_period,
+                min_execution_period,
+            } => Any::from_msg(&ThresholdDecisionPolicy {
+                threshold: threshold.clone(),
+                windows: windows(voting_period, min_execution_period),
+            })?,
+            DecisionPolicy::Percentage {
+                percentage,
+                voting_period,
+                min_execution_period,
+            } => Any::from_msg(&PercentageDecisionPolicy {
+                percentage: percentage.clone(),
+                windows: windows(voting_period, min_execution_period),
+            })?,
+        };
+
+        Ok(any)
+    }
+}
+
+/// Whether a proposal is executed as soon as it is submitted or voted on.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Exec {
+    /// The proposal is left for a separate `exec` once it passes.
+    #[default]
+    Deferred,
+    /// The proposal is executed immediately if the submission or vote makes it pass.
+    Try,
+}
+
+impl From<Exec> for i32 {
+    fn from(exec: Exec) -> Self {
+        match exec {
+            Exec::Deferred => 0,
+            Exec::Try => 1,
+        }
+    }
+}
+
+/// Converts a `std::time::Duration` into its protobuf representation.
+fn to_proto_duration(duration: &Duration) -> ProtoDuration {
+    ProtoDuration {
+        seconds: i64::try_from(duration.as_secs()).unwrap_or(i64::MAX),
+        nanos: i32::try_from(duration.subsec_nanos()).unwrap_or_default(),
+    }
+}
+
+/// Main struct providing access to Group module functions.
+#[derive(Debug, Clone)]
+pub struct Group<T: Rpc + Clone + Send + Sync> {
+    client: CosmosClient<T>,
+}
+
+impl<T: Rpc + Clone + Send + Sync> Group<T> {
+    /// Creates a new `Group` instance with the provided Cosmos client.
+    pub fn new(client: CosmosClient<T>) -> Self {
+        Self { client }
+    }
+
+    /// Fetches the policy accounts of a group with optional pagination.
+    pub async fn group_policies_by_group(
+        &self,
+        group_id: u64,
+        pagination: Option<PageRequest>,
+    ) -> CosmosResult<QueryGroupPoliciesByGroupResponse> {
+        let query = QueryGroupPoliciesByGroupRequest {
+            group_id,
+            pagination,
+        };
+        self.client
+            .query("/cosmos.group.v1.Query/GroupPoliciesByGroup", query)
+            .await
+    }
+
+    /// Fetches the info of a group.
+    pub async fn group_info(&self, group_id: u64) -> CosmosResult<QueryGroupInfoResponse> {
+        let query = QueryGroupInfoRequest { group_id };
+        self.client
+            .query("/cosmos.group.v1.Query/GroupInfo", query)
+            .await
+    }
+
+    /// Fetches the members of a group with optional pagination.
+    pub async fn group_members(
+        &self,
+        group_id: u64,
+        pagination: Option<PageRequest>,
+    ) -> CosmosResult<QueryGroupMembersResponse> {
+        let query = QueryGroupMembersRequest {
+            group_id,
+            pagination,
+        };
+        self.client
+            .query("/cosmos.group.v1.Query/GroupMembers", query)
+            .await
+    }
+
+    /// Fetches the proposals of a group policy account with optional pagination.
+    pub async fn proposals_by_group_policy(
+        &self,
+        group_policy_address: &str,
+        pagination: Option<PageRequest>,
+    ) -> CosmosResult<QueryProposalsByGroupPolicyResponse> {
+        let query = QueryProposalsByGroupPolicyRequest {
+            address: group_policy_address.to_string(),
+            pagination,
+        };
+        self.client
+            .query("/cosmos.group.v1.Query/ProposalsByGroupPolicy", query)
+            .await
+    }
+
+    /// Creates a group administered by the attached signer.
+    ///
+    /// # Errors
+    ///
+    /// * Returns `Error::NoSignerAttached` if no signer is attached to the client.
+    pub async fn create_group(
+        &self,
+        members: Vec<MemberRequest>,
+        metadata: Option<&str>,
+        memo: Option<&str>,
+        timeout_height: Option<u64>,
+    ) -> CosmosResult<Body> {
+        let signer = self.client.signer().ok_or(Error::NoSignerAttached)?;
+        let msg = Any::from_msg(&MsgCreateGroup {
+            admin: signer.public_address.to_string(),
+            members,
+            metadata: metadata.unwrap_or_default().to_string(),
+        })?;
+
+        self.client.build_tx(vec![msg], memo, timeout_height)
+    }
+
+    /// Creates a policy account for a group, administered by the attached signer.
+    ///
+    /// # Errors
+    ///
+    /// * Returns `Error::NoSignerAttached` if no signer is attached to the client.
+    pub async fn create_group_policy(
+        &self,
+        group_id: u64,
+        decision_policy: DecisionPolicy,
+        metadata: Option<&str>,
+        memo: Option<&str>,
+        timeout_height: Option<u64>,
+    ) -> CosmosResult<Body> {
+        let signer = self.client.signer().ok_or(Error::NoSignerAttached)?;
+        let msg = Any::from_msg(&MsgCreateGroupPolicy {
+            admin: signer.public_address.to_string(),
+            group_id,
+            metadata: metadata.unwrap_or_default().to_string(),
+            decision_policy: Some(decision_policy.to_any()?),
+        })?;
+
+        self.client.build_tx(vec![msg], memo, timeout_height)
+    }
+
+    /// Submits a proposal to a group policy, proposed by the attached signer, which must be a
+    /// member of the group.
+    ///
+    /// # Arguments
+    ///
+    /// * `group_policy_address`: The policy account the messages are executed from.
+    /// * `messages`: The messages to execute if the proposal passes, signed by the policy.
+    /// * `title`: The title of the proposal.
+    /// * `summary`: The summary of the proposal.
+    /// * `exec`: Whether to try executing the proposal right away.
+    /// * `memo`: An optional memo to attach to the transaction.
+    /// * `timeout_height`: The height after which the transaction can no longer be included. If
+    ///   `None`, the client's timeout height offset applies at signing time, if configured.
+    ///
+    /// # Errors
+    ///
+    /// * Returns `Error::NoSignerAttached` if no signer is attached to the client.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn submit_proposal(
+        &self,
+        group_policy_address: &str,
+        messages: Vec<Any>,
+        title: &str,
+        summary: &str,
+        exec: Exec,
+        memo: Option<&str>,
+        timeout_height: Option<u64>,
+    ) -> CosmosResult<Body> {
+        let signer = self.client.signer().ok_or(Error::NoSignerAttached)?;
+        let msg = Any::from_msg(&MsgSubmitProposal {
+            group_policy_address: group_policy_address.to_string(),
+            proposers: vec![signer.public_address.to_string()],
+            metadata: String::new(),
+            messages,
+            exec: exec.into(),
+            title: title.to_string(),
+            summary: summary.to_string(),
+        })?;
+
+        self.client.build_tx(vec![msg], memo, timeout_height)
+    }
+
+    /// Votes on a group proposal as the attached signer.
+    ///
+    /// # Errors
+    ///
+    /// * Returns `Error::NoSignerAttached` if no signer is attached to the client.
+    pub async fn vote(
+        &self,
+        proposal_id: u64,
+        option: VoteOption,
+        exec: Exec,
+        memo: Option<&str>,
+        timeout_height: Option<u64>,
+    ) -> CosmosResult<Body> {
+        let signer = self.client.signer().ok_or(Error::NoSignerAttached)?;
+        let msg = Any::from_msg(&MsgVote {
+            proposal_id,
+            voter: signer.public_address.to_string(),
+            option: option.into(),
+            metadata: String::new(),
+            exec: exec.into(),
+        })?;
+
+        self.client.build_tx(vec![msg], memo, timeout_height)
+    }
+
+    /// Executes a passed group proposal, with the attached signer as executor.
+    ///
+    /// # Errors
+    ///
+    /// * Returns `Error::NoSignerAttached` if no signer is attached to the client.
+    pub async fn exec(
+        &self,
+        proposal_id: u64,
+        memo: Option<&str>,
+        timeout_height: Option<u64>,
+    ) -> CosmosResult<Body> {
+        let signer = self.client.signer().ok_or(Error::NoSignerAttached)?;
+        let msg = Any::from_msg(&MsgExec {
+            proposal_id,
+            executor: signer.public_address.to_string(),
+        })?;
+
+        self.client.build_tx(vec![msg], memo, timeout_height)
+    }
+}
@@ -0,0 +1,252 @@
+use std::time::{Duration, UNIX_EPOCH};
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use cosmrs::proto::cosmos::base::query::v1beta1::PageRequest;
+use cosmrs::proto::cosmos::base::v1beta1::Coin;
+use cosmrs::proto::ibc::applications::transfer::v1::{
+    DenomTrace, MsgTransfer, QueryDenomTraceRequest, QueryDenomTraceResponse,
+    QueryDenomTracesRequest, QueryDenomTracesResponse,
+};
+use cosmrs::proto::ibc::core::channel::v1::{QueryChannelRequest, QueryChannelResponse};
+use cosmrs::proto::ibc::core::client::v1::Height;
+use cosmrs::tx::Body;
+use cosmrs::Any;
+use futures::future::try_join_all;
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+
+use crate::error::{CosmosResult, Error};
+use crate::{client::CosmosClient, rpc::types::Rpc};
+
+/// Timeout applied to transfers that set neither a timeout height nor a timeout timestamp.
+const DEFAULT_TRANSFER_TIMEOUT: Duration = Duration::from_secs(10 * 60);
+
+/// Main struct providing access to IBC functions.
+#[derive(Debug, Clone)]
+pub struct Ibc<T: Rpc + Clone + Send + Sync> {
+    client: CosmosClient<T>,
+}
+
+impl<T: Rpc + Clone + Send + Sync> Ibc<T> {
+    /// Creates a new `Ibc` instance with the provided Cosmos client.
+    pub fn new(client: CosmosClient<T>) -> Self {
+        Self { client }
+    }
+
+    /// Sends tokens to an account on another chain through an ICS-20 transfer channel.
+    ///
+    /// # Arguments
+    ///
+    /// * `source_port`: The port of the channel on this chain, usually `"transfer"`.
+    /// * `source_channel`: The channel on this chain, e.g. `"channel-0"`.
+    /// * `token`: The tokens to send.
+    /// * `receiver`: The receiving address on the counterparty chain.
+    /// * `timeout_height`: The counterparty height after which the packet times out.
+    /// * `timeout_timestamp`: The counterparty time, in nanoseconds since the UNIX epoch, after
+    ///   which the packet times out. If neither timeout is set, it defaults to ten minutes
+    ///   from now according to the client's clock.
+    /// * `memo`: An optional memo to attach to the transaction.
+    /// * `tx_timeout_height`: The height of this chain after which the transaction can no longer
+    ///   be included. If `None`, the client's timeout height offset applies at signing time, if
+    ///   configured.
+    ///
+    /// # Errors
+    ///
+    /// * Returns `Error::NoSignerAttached` if no signer is attached to the client.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn transfer(
+        &self,
+        source_port: &str,
+        source_channel: &str,
+        token: Coin,
+        receiver: &str,
+        timeout_height: Option<Height>,
+        timeout_timestamp: Option<u64>,
+        memo: Option<&str>,
+        tx_timeout_height: Option<u64>,
+    ) -> CosmosResult<Body> {
+        let signer = self.client.signer().ok_or(Error::NoSignerAttached)?;
+        let timeout_timestamp = match (&timeout_height, timeout_timestamp) {
+            (_, Some(timestamp)) => timestamp,
+            (Some(_), None) => 0,
+            (None, None) => self.default_timeout_timestamp(),
+        };
+
+        let msg = Any::from_msg(&MsgTransfer {
+            source_port: source_port.to_string(),
+            source_channel: source_channel.to_string(),
+            token: Some(token),
+            sender: signer.public_address.to_string(),
+            receiver: receiver.to_string(),
+            timeout_height,
+            timeout_timestamp,
+            ..Default::default()
+        })?;
+        self.client.build_tx(vec![msg], memo, tx_timeout_height)
+    }
+
+    /// Computes a timeout timestamp `DEFAULT_TRANSFER_TIMEOUT` from now, in nanoseconds since
+    /// the UNIX epoch.
+    fn default_timeout_timestamp(&self) -> u64 {
+        let since_epoch = self
+            .client
+            .clock()
+            .now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+        u64::try_from((since_epoch + DEFAULT_TRANSFER_TIMEOUT).as_nanos()).unwrap_or(u64::MAX)
+    }
+
+    /// Fetches a channel end, including its state, counterparty and connection hops.
+    pub async fn channel(
+        &self,
+        port_id: &str,
+        channel_id: &str,
+    ) -> CosmosResult<QueryChannelResponse> {
+        let query = QueryChannelRequest {
+            port_id: port_id.to_string(),
+            channel_id: channel_id.to_string(),
+        };
+        self.client
+            .query("/ibc.core.channel.v1.Query/Channel", query)
+            .await
+    }
+
+    /// Fetches the denom trace (path and base denom) behind an `ibc/{hash}` denom.
+    ///
+    /// # Arguments
+    ///
+    /// * `hash`: The trace hash, with or without the `ibc/` prefix.
+    pub async fn denom_trace(&self, hash: &str) -> CosmosResult<QueryDenomTraceResponse> {
+        let query = QueryDenomTraceRequest {
+            hash: hash.trim_start_matches("ibc/").to_string(),
+        };
+        self.client
+            .query("/ibc.applications.transfer.v1.Query/DenomTrace", query)
+            .await
+    }
+
+    /// Fetches every denom trace known to the chain, with optional pagination.
+    pub async fn denom_traces(
+        &self,
+        pagination: Option<PageRequest>,
+    ) -> CosmosResult<QueryDenomTracesResponse> {
+        let query = QueryDenomTracesRequest { pagination };
+        self.client
+            .query("/ibc.applications.transfer.v1.Query/DenomTraces", query)
+            .await
+    }
+
+    /// Resolves every `ibc/...` denom of a balance list concurrently.
+    ///
+    /// # Returns
+    ///
+    /// Each coin paired with its denom trace, or `None` for native denoms.
+    pub async fn resolve_balances(
+        &self,
+        balances: Vec<Coin>,
+    ) -> CosmosResult<Vec<(Coin, Option<DenomTrace>)>> {
+        try_join_all(balances.into_iter().map(|coin| async move {
+            if !coin.denom.starts_with("ibc/") {
+                return Ok::<_, Error>((coin, None));
+            }
+
+            let trace = self.denom_trace(&coin.denom).await?.denom_trace;
+            Ok((coin, trace))
+        }))
+        .await
+    }
+}
+
+/// JSON encoding of an ICS-04 acknowledgement as emitted in the `packet_ack` event attribute.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum Acknowledgement {
+    Result(String),
+    Error(String),
+}
+
+/// Decodes the `packet_ack` attribute of an `acknowledge_packet` or `write_acknowledgement`
+/// event.
+///
+/// # Arguments
+///
+/// * `packet_ack`: The raw JSON acknowledgement, e.g. `{"result":"AQ=="}`.
+///
+/// # Returns
+///
+/// `Ok` with the decoded result bytes if the counterparty chain processed the packet
+/// successfully, or `Err` with the error string reported by the counterparty otherwise.
+pub fn parse_acknowledgement(packet_ack: &str) -> Result<Vec<u8>, String> {
+    let ack: Acknowledgement =
+        serde_json::from_str(packet_ack).map_err(|e| format!("malformed acknowledgement: {e}"))?;
+
+    match ack {
+        Acknowledgement::Result(result) => STANDARD
+            .decode(result)
+            .map_err(|e| format!("malformed acknowledgement result: {e}")),
+        Acknowledgement::Error(error) => Err(error),
+    }
+}
+
+/// Computes the `ibc/{HASH}` denom an asset has on the destination chain, following the
+/// SDK's algorithm: the upper-case hex SHA-256 of `"{path}/{base_denom}"`.
+///
+/// # Arguments
+///
+/// * `path`: The trace path on the destination chain, e.g. `"transfer/channel-0"`. An
+///   empty path denotes a native asset, whose denom is returned unchanged.
+/// * `base_denom`: The base denomination on the source chain, e.g. `"uatom"`.
+///
+/// # Returns
+///
+/// The `ibc/{HASH}` denom, or `base_denom` itself, without an `ibc/` prefix, if `path` is
+/// empty.
+pub fn compute_denom_hash(path: &str, base_denom: &str) -> String {
+    if path.is_empty() {
+        return base_denom.to_string();
+    }
+
+    let hash = Sha256::digest(format!("{path}/{base_denom}"));
+    format!("ibc/{}", hex::encode_upper(hash))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_a_result_acknowledgement() {
+        assert_eq!(parse_acknowledgement(r#"{"result":"AQ=="}"#), Ok(vec![1]));
+    }
+
+    #[test]
+    fn parses_an_error_acknowledgement() {
+        assert_eq!(
+            parse_acknowledgement(r#"{"error":"ABCI code: 5: error handling packet"}"#),
+            Err("ABCI code: 5: error handling packet".to_string())
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_acknowledgements() {
+        for ack in [r#"{"result":"not base64!"}"#, r#"{"other":"AQ=="}"#, "AQ=="] {
+            let err = parse_acknowledgement(ack).unwrap_err();
+            assert!(err.starts_with("malformed acknowledgement"), "{err}");
+        }
+    }
+
+    #[test]
+    fn computes_the_denom_of_atom_on_osmosis() {
+        assert_eq!(
+            compute_denom_hash("transfer/channel-0", "uatom"),
+            "ibc/27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2"
+        );
+    }
+
+    #[test]
+    fn returns_native_denoms_unchanged() {
+        assert_eq!(compute_denom_hash("", "uatom"), "uatom");
+    }
+}
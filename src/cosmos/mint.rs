@@ -0,0 +1,44 @@
+use cosmrs::proto::cosmos::mint::v1beta1::{
+    QueryAnnualProvisionsRequest, QueryAnnualProvisionsResponse, QueryInflationRequest,
+    QueryInflationResponse, QueryParamsRequest, QueryParamsResponse,
+};
+
+use crate::error::CosmosResult;
+use crate::{client::CosmosClient, rpc::types::Rpc};
+
+/// Main struct providing access to Mint module functions.
+#[derive(Debug, Clone)]
+pub struct Mint<T: Rpc + Clone + Send + Sync> {
+    client: CosmosClient<T>,
+}
+
+impl<T: Rpc + Clone + Send + Sync> Mint<T> {
+    /// Creates a new `Mint` instance with the provided Cosmos client.
+    pub fn new(client: CosmosClient<T>) -> Self {
+        Self { client }
+    }
+
+    /// Fetches the current annual inflation rate.
+    pub async fn inflation(&self) -> CosmosResult<QueryInflationResponse> {
+        let query = QueryInflationRequest {};
+        self.client
+            .query("/cosmos.mint.v1beta1.Query/Inflation", query)
+            .await
+    }
+
+    /// Fetches the current annual provisions, the amount of tokens minted per year.
+    pub async fn annual_provisions(&self) -> CosmosResult<QueryAnnualProvisionsResponse> {
+        let query = QueryAnnualProvisionsRequest {};
+        self.client
+            .query("/cosmos.mint.v1beta1.Query/AnnualProvisions", query)
+            .await
+    }
+
+    /// Fetches the mint module parameters.
+    pub async fn params(&self) -> CosmosResult<QueryParamsResponse> {
+        let query = QueryParamsRequest {};
+        self.client
+            .query("/cosmos.mint.v1beta1.Query/Params", query)
+            .await
+    }
+}
@@ -1,4 +1,23 @@
 pub mod auth;
+pub mod authz;
 pub mod bank;
+pub mod consensus;
+pub mod denom;
+pub mod distribution;
+pub mod evidence;
+pub mod feegrant;
+pub mod feemarket;
+pub mod gov;
+pub mod group;
+pub mod ibc;
+pub mod mint;
+pub mod nft;
+pub mod node;
 pub mod params;
+pub mod slashing;
+pub mod staking;
+pub mod tendermint;
 pub mod tx;
+pub mod upgrade;
+#[cfg(feature = "cosmwasm")]
+pub mod wasm;
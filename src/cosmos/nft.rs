@@ -0,0 +1,210 @@
+use cosmrs::proto::cosmos::base::query::v1beta1::{PageRequest, PageResponse};
+use cosmrs::tx::Body;
+use cosmrs::Any;
+
+use crate::error::{CosmosResult, Error};
+use crate::{client::CosmosClient, rpc::types::Rpc};
+
+/// `cosmos.nft.v1beta1.NFT`: a non-fungible token of a class.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct NftToken {
+    /// The id of the class the token belongs to.
+    #[prost(string, tag = "1")]
+    pub class_id: String,
+    /// The id of the token, unique within its class.
+    #[prost(string, tag = "2")]
+    pub id: String,
+    /// URI pointing to the token's off-chain metadata.
+    #[prost(string, tag = "3")]
+    pub uri: String,
+    /// Hash of the document pointed to by `uri`.
+    #[prost(string, tag = "4")]
+    pub uri_hash: String,
+    /// App-specific data attached to the token.
+    #[prost(message, optional, tag = "10")]
+    pub data: Option<Any>,
+}
+
+/// `cosmos.nft.v1beta1.QueryBalanceRequest`.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct QueryBalanceRequest {
+    #[prost(string, tag = "1")]
+    pub class_id: String,
+    #[prost(string, tag = "2")]
+    pub owner: String,
+}
+
+/// `cosmos.nft.v1beta1.QueryBalanceResponse`.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct QueryBalanceResponse {
+    #[prost(uint64, tag = "1")]
+    pub amount: u64,
+}
+
+/// `cosmos.nft.v1beta1.QueryOwnerRequest`.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct QueryOwnerRequest {
+    #[prost(string, tag = "1")]
+    pub class_id: String,
+    #[prost(string, tag = "2")]
+    pub id: String,
+}
+
+/// `cosmos.nft.v1beta1.QueryOwnerResponse`.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct QueryOwnerResponse {
+    #[prost(string, tag = "1")]
+    pub owner: String,
+}
+
+/// `cosmos.nft.v1beta1.QueryNFTRequest`.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct QueryNftRequest {
+    #[prost(string, tag = "1")]
+    pub class_id: String,
+    #[prost(string, tag = "2")]
+    pub id: String,
+}
+
+/// `cosmos.nft.v1beta1.QueryNFTResponse`.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct QueryNftResponse {
+    #[prost(message, optional, tag = "1")]
+    pub nft: Option<NftToken>,
+}
+
+/// `cosmos.nft.v1beta1.QueryNFTsRequest`.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct QueryNftsRequest {
+    #[prost(string, tag = "1")]
+    pub class_id: String,
+    #[prost(string, tag = "2")]
+    pub owner: String,
+    #[prost(message, optional, tag = "3")]
+    pub pagination: Option<PageRequest>,
+}
+
+/// `cosmos.nft.v1beta1.QueryNFTsResponse`.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct QueryNftsResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub nfts: Vec<NftToken>,
+    #[prost(message, optional, tag = "2")]
+    pub pagination: Option<PageResponse>,
+}
+
+/// `cosmos.nft.v1beta1.MsgSend`.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MsgSend {
+    #[prost(string, tag = "1")]
+    pub class_id: String,
+    #[prost(string, tag = "2")]
+    pub id: String,
+    #[prost(string, tag = "3")]
+    pub sender: String,
+    #[prost(string, tag = "4")]
+    pub receiver: String,
+}
+
+impl prost::Name for MsgSend {
+    const NAME: &'static str = "MsgSend";
+    const PACKAGE: &'static str = "cosmos.nft.v1beta1";
+}
+
+/// Main struct providing access to NFT module (`x/nft`) functions.
+#[derive(Debug, Clone)]
+pub struct Nft<T: Rpc + Clone + Send + Sync> {
+    client: CosmosClient<T>,
+}
+
+impl<T: Rpc + Clone + Send + Sync> Nft<T> {
+    /// Creates a new `Nft` instance with the provided Cosmos client.
+    pub fn new(client: CosmosClient<T>) -> Self {
+        Self { client }
+    }
+
+    /// Fetches the number of tokens of a class held by an owner.
+    pub async fn balance(&self, class_id: &str, owner: &str) -> CosmosResult<QueryBalanceResponse> {
+        let query = QueryBalanceRequest {
+            class_id: class_id.to_string(),
+            owner: owner.to_string(),
+        };
+        self.client
+            .query("/cosmos.nft.v1beta1.Query/Balance", query)
+            .await
+    }
+
+    /// Fetches the owner of a token.
+    pub async fn owner(&self, class_id: &str, token_id: &str) -> CosmosResult<QueryOwnerResponse> {
+        let query = QueryOwnerRequest {
+            class_id: class_id.to_string(),
+            id: token_id.to_string(),
+        };
+        self.client
+            .query("/cosmos.nft.v1beta1.Query/Owner", query)
+            .await
+    }
+
+    /// Fetches a single token.
+    pub async fn nft(&self, class_id: &str, token_id: &str) -> CosmosResult<QueryNftResponse> {
+        let query = QueryNftRequest {
+            class_id: class_id.to_string(),
+            id: token_id.to_string(),
+        };
+        self.client
+            .query("/cosmos.nft.v1beta1.Query/NFT", query)
+            .await
+    }
+
+    /// Fetches the tokens of a class, of an owner, or of an owner within a class, with optional
+    /// pagination.
+    ///
+    /// # Errors
+    ///
+    /// * Returns `Error::Custom` if neither `class_id` nor `owner` is given.
+    pub async fn nfts(
+        &self,
+        class_id: Option<&str>,
+        owner: Option<&str>,
+        pagination: Option<PageRequest>,
+    ) -> CosmosResult<QueryNftsResponse> {
+        if class_id.is_none() && owner.is_none() {
+            return Err(Error::Custom(
+                "Either a class id or an owner is required to list NFTs".to_string(),
+            ));
+        }
+
+        let query = QueryNftsRequest {
+            class_id: class_id.unwrap_or_default().to_string(),
+            owner: owner.unwrap_or_default().to_string(),
+            pagination,
+        };
+        self.client
+            .query("/cosmos.nft.v1beta1.Query/NFTs", query)
+            .await
+    }
+
+    /// Transfers a token owned by the attached signer to `receiver`.
+    ///
+    /// # Errors
+    ///
+    /// * Returns `Error::NoSignerAttached` if no signer is attached to the client.
+    pub async fn send(
+        &self,
+        class_id: &str,
+        token_id: &str,
+        receiver: &str,
+        memo: Option<&str>,
+        timeout_height: Option<u64>,
+    ) -> CosmosResult<Body> {
+        let signer = self.client.signer().ok_or(Error::NoSignerAttached)?;
+        let msg = Any::from_msg(&MsgSend {
+            class_id: class_id.to_string(),
+            id: token_id.to_string(),
+            sender: signer.public_address.to_string(),
+            receiver: receiver.to_string(),
+        })?;
+
+        self.client.build_tx(vec![msg], memo, timeout_height)
+    }
+}
@@ -0,0 +1,35 @@
+use crate::error::CosmosResult;
+use crate::{client::CosmosClient, rpc::types::Rpc};
+
+/// Request type for `/cosmos.base.node.v1beta1.Service/Config`.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ConfigRequest {}
+
+/// Response type for `/cosmos.base.node.v1beta1.Service/Config`.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ConfigResponse {
+    /// The node's `minimum-gas-prices` setting, e.g. `"0.025uatom,0.1stake"`.
+    #[prost(string, tag = "1")]
+    pub minimum_gas_price: String,
+}
+
+/// Main struct providing access to the node service of the connected node.
+#[derive(Debug, Clone)]
+pub struct Node<T: Rpc + Clone + Send + Sync> {
+    client: CosmosClient<T>,
+}
+
+impl<T: Rpc + Clone + Send + Sync> Node<T> {
+    /// Creates a new `Node` instance with the provided Cosmos client.
+    pub fn new(client: CosmosClient<T>) -> Self {
+        Self { client }
+    }
+
+    /// Fetches the operator configuration of the connected node.
+    pub async fn config(&self) -> CosmosResult<ConfigResponse> {
+        let query = ConfigRequest {};
+        self.client
+            .query("/cosmos.base.node.v1beta1.Service/Config", query)
+            .await
+    }
+}
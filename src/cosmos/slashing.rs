@@ -0,0 +1,79 @@
+use cosmrs::proto::cosmos::base::query::v1beta1::PageRequest;
+use cosmrs::proto::cosmos::slashing::v1beta1::{
+    MsgUnjail, QueryParamsRequest, QueryParamsResponse, QuerySigningInfoRequest,
+    QuerySigningInfoResponse, QuerySigningInfosRequest, QuerySigningInfosResponse,
+};
+use cosmrs::tx::Body;
+use cosmrs::{AccountId, Any};
+
+use crate::error::{CosmosResult, Error};
+use crate::{client::CosmosClient, rpc::types::Rpc};
+
+/// Main struct providing access to Slashing module functions.
+#[derive(Debug, Clone)]
+pub struct Slashing<T: Rpc + Clone + Send + Sync> {
+    client: CosmosClient<T>,
+}
+
+impl<T: Rpc + Clone + Send + Sync> Slashing<T> {
+    /// Creates a new `Slashing` instance with the provided Cosmos client.
+    pub fn new(client: CosmosClient<T>) -> Self {
+        Self { client }
+    }
+
+    /// Fetches the signing info of a validator, including its missed blocks counter and the
+    /// time it is jailed until.
+    ///
+    /// # Arguments
+    ///
+    /// * `cons_address`: The consensus address (`valcons`) of the validator.
+    pub async fn signing_info(&self, cons_address: &str) -> CosmosResult<QuerySigningInfoResponse> {
+        let query = QuerySigningInfoRequest {
+            cons_address: cons_address.to_string(),
+        };
+        self.client
+            .query("/cosmos.slashing.v1beta1.Query/SigningInfo", query)
+            .await
+    }
+
+    /// Fetches the signing info of every validator.
+    pub async fn signing_infos(
+        &self,
+        pagination: Option<PageRequest>,
+    ) -> CosmosResult<QuerySigningInfosResponse> {
+        let query = QuerySigningInfosRequest { pagination };
+        self.client
+            .query("/cosmos.slashing.v1beta1.Query/SigningInfos", query)
+            .await
+    }
+
+    /// Fetches the slashing module parameters.
+    pub async fn params(&self) -> CosmosResult<QueryParamsResponse> {
+        let query = QueryParamsRequest {};
+        self.client
+            .query("/cosmos.slashing.v1beta1.Query/Params", query)
+            .await
+    }
+
+    /// Unjails the validator operated by the attached signer.
+    ///
+    /// # Errors
+    ///
+    /// * Returns `Error::NoSignerAttached` if no signer is attached to the client.
+    pub async fn unjail(
+        &self,
+        memo: Option<&str>,
+        timeout_height: Option<u64>,
+    ) -> CosmosResult<Body> {
+        let signer = self.client.signer().ok_or(Error::NoSignerAttached)?;
+        let operator = AccountId::new(
+            &format!("{}valoper", signer.public_address.prefix()),
+            &signer.public_address.to_bytes(),
+        )?;
+        let msg = Any::from_msg(&MsgUnjail {
+            validator_addr: operator.to_string(),
+        })?;
+
+        self.client.build_tx(vec![msg], memo, timeout_height)
+    }
+}
@@ -0,0 +1,376 @@
+use cosmrs::proto::cosmos::base::query::v1beta1::PageRequest;
+use cosmrs::proto::cosmos::base::v1beta1::Coin;
+use cosmrs::proto::cosmos::staking::v1beta1::{
+    BondStatus, MsgBeginRedelegate, MsgDelegate, MsgUndelegate, QueryDelegationRequest,
+    QueryDelegationResponse, QueryDelegatorDelegationsRequest, QueryDelegatorDelegationsResponse,
+    QueryHistoricalInfoRequest, QueryHistoricalInfoResponse, QueryParamsRequest,
+    QueryParamsResponse, QueryPoolRequest, QueryPoolResponse, QueryValidatorRequest,
+    QueryValidatorResponse, QueryValidatorsRequest, QueryValidatorsResponse,
+};
+use cosmrs::tx::Body;
+use cosmrs::Any;
+use futures::future::try_join_all;
+use rust_decimal::Decimal;
+
+use crate::cosmos::bank::Bank;
+use crate::cosmos::distribution::Distribution;
+use crate::cosmos::mint::Mint;
+use crate::decimal::{parse_dec, parse_dec_bytes, to_decimal, DEC_PRECISION};
+use crate::error::{CosmosResult, Error};
+use crate::pagination::paginate_all;
+use crate::{client::CosmosClient, rpc::types::Rpc};
+
+/// Main struct providing access to Staking module functions.
+#[derive(Debug, Clone)]
+pub struct Staking<T: Rpc + Clone + Send + Sync> {
+    client: CosmosClient<T>,
+}
+
+impl<T: Rpc + Clone + Send + Sync> Staking<T> {
+    /// Creates a new `Staking` instance with the provided Cosmos client.
+    pub fn new(client: CosmosClient<T>) -> Self {
+        Self { client }
+    }
+
+    /// Delegates tokens from the attached signer to a validator.
+    ///
+    /// # Arguments
+    ///
+    /// * `validator_address`: The operator address (`valoper`) of the validator.
+    /// * `amount`: The amount of tokens to delegate, in the bond denomination.
+    /// * `memo`: An optional memo to attach to the transaction.
+    /// * `timeout_height`: The height after which the transaction can no longer be included. If
+    ///   `None`, the client's timeout height offset applies at signing time, if configured.
+    ///
+    /// # Errors
+    ///
+    /// * Returns `Error::NoSignerAttached` if no signer is attached to the client.
+    pub async fn delegate(
+        &self,
+        validator_address: &str,
+        amount: Coin,
+        memo: Option<&str>,
+        timeout_height: Option<u64>,
+    ) -> CosmosResult<Body> {
+        let signer = self.client.signer().ok_or(Error::NoSignerAttached)?;
+        let msg = Any::from_msg(&MsgDelegate {
+            delegator_address: signer.public_address.to_string(),
+            validator_address: validator_address.to_string(),
+            amount: Some(amount),
+        })?;
+
+        self.client.build_tx(vec![msg], memo, timeout_height)
+    }
+
+    /// Undelegates tokens of the attached signer from a validator. The tokens are returned
+    /// once the unbonding period has elapsed.
+    ///
+    /// # Errors
+    ///
+    /// * Returns `Error::NoSignerAttached` if no signer is attached to the client.
+    pub async fn undelegate(
+        &self,
+        validator_address: &str,
+        amount: Coin,
+        memo: Option<&str>,
+        timeout_height: Option<u64>,
+    ) -> CosmosResult<Body> {
+        let signer = self.client.signer().ok_or(Error::NoSignerAttached)?;
+        let msg = Any::from_msg(&MsgUndelegate {
+            delegator_address: signer.public_address.to_string(),
+            validator_address: validator_address.to_string(),
+            amount: Some(amount),
+        })?;
+
+        self.client.build_tx(vec![msg], memo, timeout_height)
+    }
+
+    /// Moves a delegation of the attached signer from one validator to another without
+    /// unbonding.
+    ///
+    /// # Errors
+    ///
+    /// * Returns `Error::NoSignerAttached` if no signer is attached to the client.
+    pub async fn redelegate(
+        &self,
+        src_validator_address: &str,
+        dst_validator_address: &str,
+        amount: Coin,
+        memo: Option<&str>,
+        timeout_height: Option<u64>,
+    ) -> CosmosResult<Body> {
+        let signer = self.client.signer().ok_or(Error::NoSignerAttached)?;
+        let msg = Any::from_msg(&MsgBeginRedelegate {
+            delegator_address: signer.public_address.to_string(),
+            validator_src_address: src_validator_address.to_string(),
+            validator_dst_address: dst_validator_address.to_string(),
+            amount: Some(amount),
+        })?;
+
+        self.client.build_tx(vec![msg], memo, timeout_height)
+    }
+
+    /// Fetches validators, optionally filtered by bond status, with optional pagination.
+    pub async fn validators(
+        &self,
+        status: Option<BondStatus>,
+        pagination: Option<PageRequest>,
+    ) -> CosmosResult<QueryValidatorsResponse> {
+        let query = QueryValidatorsRequest {
+            status: status
+                .map(|status| status.as_str_name().to_string())
+                .unwrap_or_default(),
+            pagination,
+        };
+        self.client
+            .query("/cosmos.staking.v1beta1.Query/Validators", query)
+            .await
+    }
+
+    /// Fetches the Staking module parameters, including the bond denomination.
+    pub async fn params(&self) -> CosmosResult<QueryParamsResponse> {
+        let query = QueryParamsRequest {};
+        self.client
+            .query("/cosmos.staking.v1beta1.Query/Params", query)
+            .await
+    }
+
+    /// Fetches the amounts of bonded and not bonded tokens.
+    pub async fn pool(&self) -> CosmosResult<QueryPoolResponse> {
+        let query = QueryPoolRequest {};
+        self.client
+            .query("/cosmos.staking.v1beta1.Query/Pool", query)
+            .await
+    }
+
+    /// Computes the nominal staking APR of the chain, before validator commission.
+    ///
+    /// The APR is `inflation * (1 - community_tax) / bonded_ratio`, where `bonded_ratio` is
+    /// the share of the bond denomination's total supply that is bonded. Block proposer
+    /// rewards and fees are not included.
+    pub async fn staking_apr(&self) -> CosmosResult<Decimal> {
+        let bond_denom = self
+            .params()
+            .await?
+            .params
+            .ok_or_else(|| Error::Custom("No staking params".to_string()))?
+            .bond_denom;
+        let bonded = self.pool().await?.pool.unwrap_or_default().bonded_tokens;
+        let supply = Bank::new(self.client.clone())
+            .supply_of(&bond_denom)
+            .await?
+            .amount
+            .unwrap_or_default()
+            .amount;
+        let inflation = Mint::new(self.client.clone()).inflation().await?.inflation;
+        let community_tax = Distribution::new(self.client.clone())
+            .params()
+            .await?
+            .params
+            .unwrap_or_default()
+            .community_tax;
+
+        let parse_int = |value: &str| {
+            value
+                .parse::<u128>()
+                .map_err(|e| Error::wrap(format!("Invalid token amount {value}"), e))
+        };
+        let bonded = to_decimal(parse_int(&bonded)?, 0)?;
+        let supply = to_decimal(parse_int(&supply)?, 0)?;
+        if bonded.is_zero() || supply.is_zero() {
+            return Ok(Decimal::ZERO);
+        }
+
+        let precision = DEC_PRECISION as u32;
+        let inflation = to_decimal(parse_dec_bytes(&inflation)?, precision)?;
+        let community_tax = if community_tax.is_empty() {
+            Decimal::ZERO
+        } else {
+            to_decimal(parse_dec(&community_tax)?, precision)?
+        };
+
+        let bonded_ratio = bonded / supply;
+        Ok(inflation * (Decimal::ONE - community_tax) / bonded_ratio)
+    }
+
+    /// Computes the APR a delegator earns with a specific validator, i.e. the chain-wide
+    /// `staking_apr` reduced by the validator's commission: `apr * (1 - commission_rate)`.
+    pub async fn validator_apr(&self, validator_address: &str) -> CosmosResult<Decimal> {
+        let validator = self
+            .validator(validator_address)
+            .await?
+            .validator
+            .ok_or_else(|| Error::Custom(format!("Validator {validator_address} not found")))?;
+        let rate = validator
+            .commission
+            .and_then(|commission| commission.commission_rates)
+            .map(|rates| rates.rate)
+            .unwrap_or_default();
+        let rate = if rate.is_empty() {
+            Decimal::ZERO
+        } else {
+            to_decimal(parse_dec(&rate)?, DEC_PRECISION as u32)?
+        };
+
+        Ok(self.staking_apr().await? * (Decimal::ONE - rate))
+    }
+
+    /// Fetches information about a specific validator.
+    pub async fn validator(&self, validator_address: &str) -> CosmosResult<QueryValidatorResponse> {
+        let query = QueryValidatorRequest {
+            validator_addr: validator_address.to_string(),
+        };
+        self.client
+            .query("/cosmos.staking.v1beta1.Query/Validator", query)
+            .await
+    }
+
+    /// Fetches the delegation of a delegator to a specific validator.
+    pub async fn delegation(
+        &self,
+        delegator_address: &str,
+        validator_address: &str,
+    ) -> CosmosResult<QueryDelegationResponse> {
+        let query = QueryDelegationRequest {
+            delegator_addr: delegator_address.to_string(),
+            validator_addr: validator_address.to_string(),
+        };
+        self.client
+            .query("/cosmos.staking.v1beta1.Query/Delegation", query)
+            .await
+    }
+
+    /// Fetches all delegations of a delegator with optional pagination.
+    pub async fn delegator_delegations(
+        &self,
+        delegator_address: &str,
+        pagination: Option<PageRequest>,
+    ) -> CosmosResult<QueryDelegatorDelegationsResponse> {
+        let query = QueryDelegatorDelegationsRequest {
+            delegator_addr: delegator_address.to_string(),
+            pagination,
+        };
+        self.client
+            .query("/cosmos.staking.v1beta1.Query/DelegatorDelegations", query)
+            .await
+    }
+
+    /// Fetches the header and validator set the staking module stored at the given height.
+    ///
+    /// Only the most recent `historical_entries` heights (a staking param) are retained.
+    pub async fn historical_info(&self, height: i64) -> CosmosResult<QueryHistoricalInfoResponse> {
+        let query = QueryHistoricalInfoRequest { height };
+        self.client
+            .query("/cosmos.staking.v1beta1.Query/HistoricalInfo", query)
+            .await
+    }
+
+    /// Fetches a delegation and converts its shares to tokens using the validator's current
+    /// exchange rate (`tokens / delegator_shares`).
+    ///
+    /// # Returns
+    ///
+    /// The delegated amount in the bond denomination, truncated to an integer.
+    pub async fn delegation_tokens(
+        &self,
+        delegator_address: &str,
+        validator_address: &str,
+    ) -> CosmosResult<Coin> {
+        let delegation = self
+            .delegation(delegator_address, validator_address)
+            .await?
+            .delegation_response
+            .ok_or_else(|| {
+                Error::Custom(format!(
+                    "No delegation from {delegator_address} to {validator_address}"
+                ))
+            })?;
+        let validator = self
+            .validator(validator_address)
+            .await?
+            .validator
+            .ok_or_else(|| Error::Custom(format!("Validator {validator_address} not found")))?;
+
+        let shares = delegation.delegation.unwrap_or_default().shares;
+        let amount = shares_to_tokens(&shares, &validator.tokens, &validator.delegator_shares)?;
+
+        Ok(Coin {
+            denom: delegation.balance.unwrap_or_default().denom,
+            amount: amount.to_string(),
+        })
+    }
+
+    /// Fetches every delegation of a delegator, converted to tokens using each validator's
+    /// current exchange rate.
+    ///
+    /// # Returns
+    ///
+    /// A list of `(validator operator address, delegated tokens)` pairs.
+    pub async fn all_delegations_in_tokens(
+        &self,
+        delegator_address: &str,
+    ) -> CosmosResult<Vec<(String, Coin)>> {
+        let delegations = paginate_all(
+            |page| self.delegator_delegations(delegator_address, Some(page)),
+            |resp| (resp.delegation_responses, resp.pagination),
+        )
+        .await?;
+
+        try_join_all(delegations.into_iter().map(|resp| async move {
+            let delegation = resp.delegation.unwrap_or_default();
+            let validator = self
+                .validator(&delegation.validator_address)
+                .await?
+                .validator
+                .ok_or_else(|| {
+                    Error::Custom(format!(
+                        "Validator {} not found",
+                        delegation.validator_address
+                    ))
+                })?;
+            let amount = shares_to_tokens(
+                &delegation.shares,
+                &validator.tokens,
+                &validator.delegator_shares,
+            )?;
+
+            Ok::<_, Error>((
+                delegation.validator_address,
+                Coin {
+                    denom: resp.balance.unwrap_or_default().denom,
+                    amount: amount.to_string(),
+                },
+            ))
+        }))
+        .await
+    }
+}
+
+/// Converts delegation shares to tokens given a validator's total tokens and shares.
+fn shares_to_tokens(shares: &str, tokens: &str, delegator_shares: &str) -> CosmosResult<u128> {
+    let shares = parse_dec(shares)?;
+    let delegator_shares = parse_dec(delegator_shares)?;
+    let tokens = tokens
+        .parse::<u128>()
+        .map_err(|e| Error::wrap(format!("Invalid token amount {tokens}"), e))?;
+
+    if delegator_shares == 0 {
+        return Ok(0);
+    }
+
+    Ok(mul_ratio(tokens, shares, delegator_shares))
+}
+
+/// Computes `amount * numerator / denominator`, dropping low digits of the ratio when the
+/// intermediate product would overflow.
+fn mul_ratio(amount: u128, mut numerator: u128, mut denominator: u128) -> u128 {
+    while denominator > 0 {
+        if let Some(product) = amount.checked_mul(numerator) {
+            return product / denominator;
+        }
+        numerator /= 10;
+        denominator /= 10;
+    }
+
+    0
+}
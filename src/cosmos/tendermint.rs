@@ -0,0 +1,119 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use cosmrs::proto::cosmos::base::query::v1beta1::PageRequest;
+use cosmrs::proto::cosmos::base::tendermint::v1beta1::{
+    GetBlockByHeightRequest, GetBlockByHeightResponse, GetLatestBlockRequest,
+    GetLatestBlockResponse, GetLatestValidatorSetRequest, GetLatestValidatorSetResponse,
+    GetNodeInfoRequest, GetNodeInfoResponse,
+};
+use cosmrs::proto::tendermint::types::Block;
+
+use crate::error::{CosmosResult, Error};
+use crate::{client::CosmosClient, rpc::types::Rpc};
+
+/// Header fields of a block commonly needed by clients.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlockHeader {
+    /// Chain ID the block belongs to.
+    pub chain_id: String,
+    /// Height of the block.
+    pub height: u64,
+    /// Time the block was proposed.
+    pub time: SystemTime,
+    /// Hex encoded consensus address of the block proposer.
+    pub proposer_address: String,
+}
+
+impl TryFrom<Option<Block>> for BlockHeader {
+    type Error = Error;
+
+    fn try_from(block: Option<Block>) -> CosmosResult<Self> {
+        let header = block
+            .and_then(|block| block.header)
+            .ok_or_else(|| Error::Custom("Block has no header".to_string()))?;
+        let time = header
+            .time
+            .ok_or_else(|| Error::Custom("Block has no time".to_string()))?;
+
+        Ok(BlockHeader {
+            chain_id: header.chain_id,
+            height: u64::try_from(header.height).unwrap_or_default(),
+            time: UNIX_EPOCH
+                + Duration::new(
+                    u64::try_from(time.seconds).unwrap_or_default(),
+                    u32::try_from(time.nanos).unwrap_or_default(),
+                ),
+            proposer_address: hex::encode_upper(header.proposer_address),
+        })
+    }
+}
+
+/// Main struct providing access to the Tendermint (CometBFT) service functions.
+#[derive(Debug, Clone)]
+pub struct Tendermint<T: Rpc + Clone + Send + Sync> {
+    client: CosmosClient<T>,
+}
+
+impl<T: Rpc + Clone + Send + Sync> Tendermint<T> {
+    /// Creates a new `Tendermint` instance with the provided Cosmos client.
+    pub fn new(client: CosmosClient<T>) -> Self {
+        Self { client }
+    }
+
+    /// Fetches the latest block.
+    pub async fn latest_block(&self) -> CosmosResult<GetLatestBlockResponse> {
+        let query = GetLatestBlockRequest {};
+        self.client
+            .query(
+                "/cosmos.base.tendermint.v1beta1.Service/GetLatestBlock",
+                query,
+            )
+            .await
+    }
+
+    /// Fetches the block at the given height.
+    pub async fn block_by_height(&self, height: u64) -> CosmosResult<GetBlockByHeightResponse> {
+        let query = GetBlockByHeightRequest {
+            height: i64::try_from(height).unwrap_or(i64::MAX),
+        };
+        self.client
+            .query(
+                "/cosmos.base.tendermint.v1beta1.Service/GetBlockByHeight",
+                query,
+            )
+            .await
+    }
+
+    /// Fetches the header of the latest block.
+    pub async fn latest_header(&self) -> CosmosResult<BlockHeader> {
+        BlockHeader::try_from(self.latest_block().await?.block)
+    }
+
+    /// Fetches the header of the block at the given height.
+    pub async fn header_by_height(&self, height: u64) -> CosmosResult<BlockHeader> {
+        BlockHeader::try_from(self.block_by_height(height).await?.block)
+    }
+
+    /// Fetches the node information of the connected node, including the network (chain ID)
+    /// and application version.
+    pub async fn node_info(&self) -> CosmosResult<GetNodeInfoResponse> {
+        let query = GetNodeInfoRequest {};
+        self.client
+            .query("/cosmos.base.tendermint.v1beta1.Service/GetNodeInfo", query)
+            .await
+    }
+
+    /// Fetches the validator set at the latest height.
+    pub async fn latest_validator_set(
+        &self,
+        pagination: Option<PageRequest>,
+    ) -> CosmosResult<GetLatestValidatorSetResponse> {
+        let query = GetLatestValidatorSetRequest { pagination };
+        self.client
+            .query(
+                "/cosmos.base.tendermint.v1beta1.Service/GetLatestValidatorSet",
+                query,
+            )
+            .await
+    }
+}
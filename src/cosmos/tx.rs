@@ -1,12 +1,26 @@
-use std::thread::sleep;
 use std::time::Duration;
 
+use crate::cosmos::consensus::Consensus;
 use crate::error::{CosmosResult, Error};
-use crate::rpc::types::{TxAsyncResponse, TxSyncResponse};
+use crate::rpc::types::{is_not_found_error, TxAsyncResponse, TxSyncResponse};
+use crate::tx::check_tx_result;
 use crate::{client::CosmosClient, rpc::types::Rpc};
-use cosmrs::proto::cosmos::tx::v1beta1::{GetTxRequest, GetTxResponse, SimulateResponse};
+use cosmrs::proto::cosmos::base::v1beta1::Coin;
+use cosmrs::proto::cosmos::tx::v1beta1::{
+    AuthInfo, Fee, GetTxRequest, GetTxResponse, SignerInfo, SimulateResponse, TxBody, TxRaw,
+};
+use cosmrs::proto::prost::{Message, Name};
 use cosmrs::rpc::endpoint::broadcast::{tx_async, tx_sync};
-use cosmrs::tx::Body;
+use cosmrs::tx::{Body, BodyBuilder};
+use cosmrs::Any;
+use futures::stream::{self, Stream};
+use sha2::{Digest, Sha256};
+
+/// Maximum number of times a transaction is looked up while waiting for it to be included.
+const POLL_ATTEMPTS: u32 = 60;
+
+/// Delay between two lookups of a pending transaction.
+pub(crate) const POLL_INTERVAL: Duration = Duration::from_secs(1);
 
 /// Enum representing different responses for broadcast transactions.
 #[derive(Clone, Debug)]
@@ -17,6 +31,45 @@ pub enum Response {
     Sync(tx_sync::Response),
 }
 
+/// Status of a transaction being watched with `Tx::watch`.
+#[derive(Clone, Debug)]
+pub enum TxStatus {
+    /// The node reports no block including the transaction yet.
+    ///
+    /// This does not tell whether the transaction is pending in the mempool: mempool contents
+    /// cannot be queried over gRPC, so they are not inspected.
+    NotFound,
+    /// The transaction was included in a block.
+    Confirmed(Box<GetTxResponse>),
+}
+
+/// A transaction decoded from its raw `TxRaw` bytes.
+#[derive(Clone, Debug)]
+pub struct DecodedTx {
+    /// Messages of the transaction, still encoded; see `decode_msg`.
+    pub messages: Vec<Any>,
+    /// Memo of the transaction.
+    pub memo: String,
+    /// Height after which the transaction is no longer valid, `0` if unset.
+    pub timeout_height: u64,
+    /// Fee paid by the transaction.
+    pub fee: Option<Fee>,
+    /// Public keys, sign modes and sequences of the signers.
+    pub signer_infos: Vec<SignerInfo>,
+    /// Signatures, in the order of `signer_infos`.
+    pub signatures: Vec<Vec<u8>>,
+}
+
+impl DecodedTx {
+    /// Decodes every message of type `M`, skipping messages of other types.
+    pub fn msgs<M: Message + Name + Default>(&self) -> CosmosResult<Vec<M>> {
+        self.messages
+            .iter()
+            .filter_map(|msg| decode_msg::<M>(msg).transpose())
+            .collect()
+    }
+}
+
 /// Main struct providing access to Tx module functions.
 #[derive(Debug, Clone)]
 pub struct Tx<T: Rpc + Clone + Send + Sync> {
@@ -34,16 +87,60 @@ impl<T: Rpc + Clone + Send + Sync> Tx<T> {
         self.client.simulate_tx(payload).await
     }
 
+    /// Estimates how many copies of a message fit in a single transaction without exceeding the
+    /// block gas limit.
+    ///
+    /// Simulates batches of one and two messages to extrapolate the fixed and per-message gas
+    /// cost, instead of simulating the full batch.
+    ///
+    /// # Arguments
+    ///
+    /// * `sample_msg`: A message representative of the batch.
+    /// * `safety_margin`: Fraction of the block gas limit to keep unused, e.g. `0.1` for 10%.
+    ///
+    /// # Errors
+    ///
+    /// * Returns `Error::CannotSimulateTxGas` if a simulation does not report gas usage.
+    /// * Returns `Error::Custom` if the chain does not limit block gas.
+    pub async fn max_msgs_per_tx(&self, sample_msg: Any, safety_margin: f64) -> CosmosResult<u64> {
+        let max_gas = Consensus::new(self.client.clone())
+            .params()
+            .await?
+            .params
+            .and_then(|params| params.block)
+            .map(|block| block.max_gas)
+            .ok_or_else(|| Error::Custom("No block consensus params".to_string()))?;
+        let max_gas = u64::try_from(max_gas)
+            .map_err(|_| Error::Custom("Block gas is unlimited".to_string()))?;
+
+        let single = self.simulated_gas(vec![sample_msg.clone()]).await?;
+        let double = self
+            .simulated_gas(vec![sample_msg.clone(), sample_msg])
+            .await?;
+        let per_msg = double.saturating_sub(single).max(1);
+        let base = single.saturating_sub(per_msg);
+
+        let usable = (max_gas as f64 * (1.0 - safety_margin.clamp(0.0, 1.0))) as u64;
+        Ok(usable.saturating_sub(base) / per_msg)
+    }
+
+    /// Simulates a transaction made of `msgs` and returns the gas it used.
+    async fn simulated_gas(&self, msgs: Vec<Any>) -> CosmosResult<u64> {
+        let body = BodyBuilder::new().msgs(msgs).finish();
+        self.simulate(body)
+            .await?
+            .gas_info
+            .map(|info| info.gas_used)
+            .ok_or(Error::CannotSimulateTxGas)
+    }
+
     /// Broadcasts a transaction synchronously.
     pub async fn broadcast_tx_sync(&self, body: Body) -> CosmosResult<TxSyncResponse> {
         let sync_resp = self.client.broadcast_tx_sync(body).await?;
+
         let poll_resp = self.poll_for_tx(&sync_resp.hash.to_string()).await?;
         let tx_resp = poll_resp.tx_response.ok_or(Error::NoneTxResponse)?;
-
-        let raw_log = tx_resp.raw_log;
-        if raw_log.contains("failed") {
-            return Err(Error::Custom(raw_log));
-        }
+        check_tx_result(&tx_resp.codespace, tx_resp.code, &tx_resp.raw_log)?;
 
         if tx_resp.gas_used > tx_resp.gas_wanted {
             return Err(Error::OutOfGas);
@@ -71,18 +168,153 @@ impl<T: Rpc + Clone + Send + Sync> Tx<T> {
     /// Polls for a transaction until it is found or a timeout is reached.
     ///
     /// This function repeatedly calls `get_tx` to check the status of a transaction identified by its hash.
-    /// It will continue polling for up to 60 iterations, with a 3-second sleep between each attempt.
+    /// It will continue polling for up to 60 iterations, with a 1-second sleep between each attempt.
     pub async fn poll_for_tx(&self, hash: &str) -> CosmosResult<GetTxResponse> {
-        for _ in 0..60 {
+        self.poll_for_tx_with(hash, POLL_ATTEMPTS, POLL_INTERVAL)
+            .await
+    }
+
+    /// Polls for a transaction up to `attempts` times, waiting `interval` between attempts.
+    ///
+    /// Polling stops as soon as the transaction is included in a block, including when it
+    /// failed: check `tx_response.code` of the result, as a failed transaction will not
+    /// succeed later.
+    ///
+    /// # Errors
+    ///
+    /// * Returns `Error::TXPollingTimeout` if the transaction is still not found after the last
+    ///   attempt.
+    pub async fn poll_for_tx_with(
+        &self,
+        hash: &str,
+        attempts: u32,
+        interval: Duration,
+    ) -> CosmosResult<GetTxResponse> {
+        for attempt in 0..attempts {
             let tx = self.get_tx(hash).await;
 
             if tx.is_ok() {
                 return tx;
             }
 
-            sleep(Duration::from_secs(1));
+            if attempt + 1 < attempts {
+                self.client.clock().sleep(interval).await;
+            }
         }
 
         Err(Error::TXPollingTimeout)
     }
+
+    /// Watches a transaction until it is included in a block, emitting its status on each poll.
+    ///
+    /// The stream yields `TxStatus::NotFound` each time the node reports the transaction as not
+    /// found, then ends with either `TxStatus::Confirmed` or `Error::TXPollingTimeout` once the
+    /// attempts are exhausted. Any other lookup error is yielded as is and ends the stream.
+    pub fn watch(&self, hash: &str) -> impl Stream<Item = CosmosResult<TxStatus>> + '_ {
+        let hash = hash.to_string();
+        stream::unfold((0, false), move |(attempt, done)| {
+            let hash = hash.clone();
+            async move {
+                if done {
+                    return None;
+                }
+                if attempt >= POLL_ATTEMPTS {
+                    return Some((Err(Error::TXPollingTimeout), (attempt, true)));
+                }
+                if attempt > 0 {
+                    self.client.clock().sleep(POLL_INTERVAL).await;
+                }
+
+                match self.get_tx(&hash).await {
+                    Ok(resp) => Some((Ok(TxStatus::Confirmed(Box::new(resp))), (attempt, true))),
+                    Err(e) if is_not_found_error(&e) => {
+                        Some((Ok(TxStatus::NotFound), (attempt + 1, false)))
+                    }
+                    Err(e) => Some((Err(e), (attempt, true))),
+                }
+            }
+        })
+    }
+}
+
+/// Verifies that raw transaction bytes hash to the expected transaction hash, e.g. to check
+/// that an untrusted RPC endpoint returned the transaction that was asked for.
+///
+/// # Arguments
+///
+/// * `tx_bytes`: The raw `TxRaw` bytes of the transaction.
+/// * `expected_hash`: The hex-encoded transaction hash, in either case.
+pub fn verify_hash(tx_bytes: &[u8], expected_hash: &str) -> bool {
+    let hash = Sha256::digest(tx_bytes);
+    hex::encode(hash).eq_ignore_ascii_case(expected_hash)
+}
+
+/// Decodes raw `TxRaw` bytes, e.g. from a block or the mempool, into its messages, memo,
+/// fee and signer infos.
+pub fn decode_tx(tx_bytes: &[u8]) -> CosmosResult<DecodedTx> {
+    let raw = TxRaw::decode(tx_bytes)?;
+    let body = TxBody::decode(raw.body_bytes.as_slice())?;
+    let auth_info = AuthInfo::decode(raw.auth_info_bytes.as_slice())?;
+
+    Ok(DecodedTx {
+        messages: body.messages,
+        memo: body.memo,
+        timeout_height: body.timeout_height,
+        fee: auth_info.fee,
+        signer_infos: auth_info.signer_infos,
+        signatures: raw.signatures,
+    })
+}
+
+/// Decodes a message into the concrete type `M` if its type URL matches.
+///
+/// # Returns
+///
+/// `None` if the message is of another type, or `Error::DecodeError` if it matches but cannot
+/// be decoded.
+pub fn decode_msg<M: Message + Name + Default>(msg: &Any) -> CosmosResult<Option<M>> {
+    if msg.type_url != M::type_url() {
+        return Ok(None);
+    }
+
+    Ok(Some(M::decode(msg.value.as_slice())?))
+}
+
+/// Extracts the fee the chain deducted for a confirmed transaction from its decoded auth info.
+///
+/// # Returns
+///
+/// The fee coins, or `None` if the response does not contain the transaction's auth info.
+pub fn fee_paid(resp: &GetTxResponse) -> Option<Vec<Coin>> {
+    resp.tx
+        .as_ref()
+        .and_then(|tx| tx.auth_info.as_ref())
+        .and_then(|auth_info| auth_info.fee.as_ref())
+        .map(|fee| fee.amount.clone())
+}
+
+/// Computes the effective gas price paid by a confirmed transaction for each fee denomination,
+/// i.e. the fee amount divided by the gas actually used.
+///
+/// # Returns
+///
+/// A list of `(denom, price per gas unit)` pairs, or `Error::NoneTxResponse` if the response
+/// does not contain the transaction and its result.
+pub fn effective_gas_price(resp: &GetTxResponse) -> CosmosResult<Vec<(String, f64)>> {
+    let tx_resp = resp.tx_response.as_ref().ok_or(Error::NoneTxResponse)?;
+    let fee = fee_paid(resp).ok_or(Error::NoneTxResponse)?;
+
+    if tx_resp.gas_used <= 0 {
+        return Ok(Vec::new());
+    }
+
+    fee.iter()
+        .map(|coin| {
+            let amount = coin
+                .amount
+                .parse::<f64>()
+                .map_err(|e| Error::wrap(format!("Invalid fee amount {}", coin.amount), e))?;
+            Ok((coin.denom.clone(), amount / tx_resp.gas_used as f64))
+        })
+        .collect()
 }
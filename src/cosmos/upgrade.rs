@@ -0,0 +1,60 @@
+use cosmrs::proto::cosmos::upgrade::v1beta1::{
+    Plan, QueryAppliedPlanRequest, QueryAppliedPlanResponse, QueryCurrentPlanRequest,
+    QueryCurrentPlanResponse, QueryModuleVersionsRequest, QueryModuleVersionsResponse,
+};
+
+use crate::error::CosmosResult;
+use crate::{client::CosmosClient, rpc::types::Rpc};
+
+/// Main struct providing access to Upgrade module functions.
+///
+/// Upgrades are scheduled through governance proposals, so the module only exposes queries.
+#[derive(Debug, Clone)]
+pub struct Upgrade<T: Rpc + Clone + Send + Sync> {
+    client: CosmosClient<T>,
+}
+
+impl<T: Rpc + Clone + Send + Sync> Upgrade<T> {
+    /// Creates a new `Upgrade` instance with the provided Cosmos client.
+    pub fn new(client: CosmosClient<T>) -> Self {
+        Self { client }
+    }
+
+    /// Fetches the currently scheduled upgrade plan, if any.
+    pub async fn current_plan(&self) -> CosmosResult<Option<Plan>> {
+        let query = QueryCurrentPlanRequest {};
+        let resp: QueryCurrentPlanResponse = self
+            .client
+            .query("/cosmos.upgrade.v1beta1.Query/CurrentPlan", query)
+            .await?;
+
+        Ok(resp.plan)
+    }
+
+    /// Fetches the height at which the named upgrade was applied, or `None` if it has not been
+    /// applied.
+    pub async fn applied_plan(&self, name: &str) -> CosmosResult<Option<u64>> {
+        let query = QueryAppliedPlanRequest {
+            name: name.to_string(),
+        };
+        let resp: QueryAppliedPlanResponse = self
+            .client
+            .query("/cosmos.upgrade.v1beta1.Query/AppliedPlan", query)
+            .await?;
+
+        Ok(u64::try_from(resp.height).ok().filter(|height| *height > 0))
+    }
+
+    /// Fetches the consensus version of every module, or of `module_name` only if given.
+    pub async fn module_versions(
+        &self,
+        module_name: Option<&str>,
+    ) -> CosmosResult<QueryModuleVersionsResponse> {
+        let query = QueryModuleVersionsRequest {
+            module_name: module_name.unwrap_or_default().to_string(),
+        };
+        self.client
+            .query("/cosmos.upgrade.v1beta1.Query/ModuleVersions", query)
+            .await
+    }
+}
@@ -0,0 +1,176 @@
+use cosmrs::proto::cosmos::base::v1beta1::Coin;
+use cosmrs::proto::cosmwasm::wasm::v1::{
+    MsgExecuteContract, MsgInstantiateContract, MsgStoreCode, QueryContractInfoRequest,
+    QueryContractInfoResponse, QuerySmartContractStateRequest, QuerySmartContractStateResponse,
+};
+use cosmrs::tx::Body;
+use cosmrs::Any;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::client::CosmosClient;
+use crate::error::{CosmosResult, Error};
+use crate::rpc::types::{is_not_found_error, Rpc};
+
+/// Main struct providing access to CosmWasm module functions.
+#[derive(Debug, Clone)]
+pub struct Wasm<T: Rpc + Clone + Send + Sync> {
+    client: CosmosClient<T>,
+}
+
+impl<T: Rpc + Clone + Send + Sync> Wasm<T> {
+    /// Creates a new `Wasm` instance with the provided Cosmos client.
+    pub fn new(client: CosmosClient<T>) -> Self {
+        Self { client }
+    }
+
+    /// Fetches the metadata of an instantiated contract, including its code id.
+    pub async fn contract_info(&self, address: &str) -> CosmosResult<QueryContractInfoResponse> {
+        let query = QueryContractInfoRequest {
+            address: address.to_string(),
+        };
+        self.client
+            .query("/cosmwasm.wasm.v1.Query/ContractInfo", query)
+            .await
+    }
+
+    /// Runs a smart query against a contract.
+    ///
+    /// # Arguments
+    ///
+    /// * `contract`: The contract address.
+    /// * `query`: The query message, serialized to JSON.
+    ///
+    /// # Returns
+    ///
+    /// The contract's JSON response deserialized into `R`.
+    pub async fn smart_query<Q: Serialize, R: DeserializeOwned>(
+        &self,
+        contract: &str,
+        query: &Q,
+    ) -> CosmosResult<R> {
+        let query = QuerySmartContractStateRequest {
+            address: contract.to_string(),
+            query_data: serde_json::to_vec(query)?,
+        };
+        let resp: QuerySmartContractStateResponse = self
+            .client
+            .query("/cosmwasm.wasm.v1.Query/SmartContractState", query)
+            .await?;
+
+        Ok(serde_json::from_slice(&resp.data)?)
+    }
+
+    /// Executes a contract with the attached signer as sender.
+    ///
+    /// # Arguments
+    ///
+    /// * `contract`: The contract address.
+    /// * `msg`: The execute message, serialized to JSON.
+    /// * `funds`: Tokens sent to the contract along with the message.
+    /// * `memo`: An optional memo to attach to the transaction.
+    /// * `timeout_height`: The height after which the transaction can no longer be included. If
+    ///   `None`, the client's timeout height offset applies at signing time, if configured.
+    ///
+    /// # Errors
+    ///
+    /// * Returns `Error::NoSignerAttached` if no signer is attached to the client.
+    pub async fn execute<M: Serialize>(
+        &self,
+        contract: &str,
+        msg: &M,
+        funds: Vec<Coin>,
+        memo: Option<&str>,
+        timeout_height: Option<u64>,
+    ) -> CosmosResult<Body> {
+        let signer = self.client.signer().ok_or(Error::NoSignerAttached)?;
+        let msg = Any::from_msg(&MsgExecuteContract {
+            sender: signer.public_address.to_string(),
+            contract: contract.to_string(),
+            msg: serde_json::to_vec(msg)?,
+            funds,
+        })?;
+
+        self.client.build_tx(vec![msg], memo, timeout_height)
+    }
+
+    /// Instantiates a contract from uploaded code, with the attached signer as sender.
+    ///
+    /// # Arguments
+    ///
+    /// * `code_id`: The id of the uploaded code.
+    /// * `msg`: The instantiate message, serialized to JSON.
+    /// * `label`: A human readable label for the contract.
+    /// * `admin`: The account allowed to migrate the contract, if any.
+    /// * `funds`: Tokens sent to the contract on instantiation.
+    /// * `memo`: An optional memo to attach to the transaction.
+    /// * `timeout_height`: The height after which the transaction can no longer be included. If
+    ///   `None`, the client's timeout height offset applies at signing time, if configured.
+    ///
+    /// # Errors
+    ///
+    /// * Returns `Error::NoSignerAttached` if no signer is attached to the client.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn instantiate<M: Serialize>(
+        &self,
+        code_id: u64,
+        msg: &M,
+        label: &str,
+        admin: Option<&str>,
+        funds: Vec<Coin>,
+        memo: Option<&str>,
+        timeout_height: Option<u64>,
+    ) -> CosmosResult<Body> {
+        let signer = self.client.signer().ok_or(Error::NoSignerAttached)?;
+        let msg = Any::from_msg(&MsgInstantiateContract {
+            sender: signer.public_address.to_string(),
+            admin: admin.unwrap_or_default().to_string(),
+            code_id,
+            label: label.to_string(),
+            msg: serde_json::to_vec(msg)?,
+            funds,
+        })?;
+
+        self.client.build_tx(vec![msg], memo, timeout_height)
+    }
+
+    /// Uploads contract code, with the attached signer as sender.
+    ///
+    /// # Arguments
+    ///
+    /// * `wasm_byte_code`: The contract code, raw or gzip compressed.
+    /// * `memo`: An optional memo to attach to the transaction.
+    /// * `timeout_height`: The height after which the transaction can no longer be included. If
+    ///   `None`, the client's timeout height offset applies at signing time, if configured.
+    ///
+    /// # Errors
+    ///
+    /// * Returns `Error::NoSignerAttached` if no signer is attached to the client.
+    pub async fn store_code(
+        &self,
+        wasm_byte_code: Vec<u8>,
+        memo: Option<&str>,
+        timeout_height: Option<u64>,
+    ) -> CosmosResult<Body> {
+        let signer = self.client.signer().ok_or(Error::NoSignerAttached)?;
+        let msg = Any::from_msg(&MsgStoreCode {
+            sender: signer.public_address.to_string(),
+            wasm_byte_code,
+            instantiate_permission: None,
+        })?;
+
+        self.client.build_tx(vec![msg], memo, timeout_height)
+    }
+
+    /// Checks whether an address belongs to an instantiated contract.
+    ///
+    /// Returns `false` rather than an error when the chain reports that no contract exists at
+    /// the address; transport errors are still propagated.
+    pub async fn is_contract(&self, address: &str) -> CosmosResult<bool> {
+        match self.contract_info(address).await {
+            Ok(resp) => Ok(resp.contract_info.is_some()),
+            Err(e) if is_not_found_error(&e) => Ok(false),
+            Err(e) => Err(e),
+        }
+    }
+}
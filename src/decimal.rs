@@ -0,0 +1,51 @@
+use rust_decimal::Decimal;
+
+use crate::error::{CosmosResult, Error};
+
+/// Number of fractional digits of the SDK's `LegacyDec`.
+pub(crate) const DEC_PRECISION: usize = 18;
+
+/// Scaling factor of the 18-decimal fixed point representation.
+pub(crate) const DEC_ONE: u128 = 1_000_000_000_000_000_000;
+
+/// Parses a `LegacyDec` into its 18-decimal fixed point integer representation.
+///
+/// Protobuf responses carry the already-scaled integer, while JSON renderings contain a
+/// decimal point; both are accepted.
+pub(crate) fn parse_dec(value: &str) -> CosmosResult<u128> {
+    if value.contains('.') {
+        return parse_decimal(value);
+    }
+
+    value
+        .parse()
+        .map_err(|e| Error::wrap(format!("Invalid decimal {value}"), e))
+}
+
+/// Parses a human readable decimal such as `"0.025"` or `"3"` into its 18-decimal fixed point
+/// integer representation.
+pub(crate) fn parse_decimal(value: &str) -> CosmosResult<u128> {
+    let invalid = || Error::Custom(format!("Invalid decimal {value}"));
+    let (int, frac) = value.split_once('.').unwrap_or((value, ""));
+    if frac.len() > DEC_PRECISION || (int.is_empty() && frac.is_empty()) {
+        return Err(invalid());
+    }
+
+    format!("{int}{frac:0<DEC_PRECISION$}")
+        .parse()
+        .map_err(|_| invalid())
+}
+
+/// Parses a `LegacyDec` encoded as UTF-8 bytes, as returned by some SDK queries.
+pub(crate) fn parse_dec_bytes(value: &[u8]) -> CosmosResult<u128> {
+    let value = std::str::from_utf8(value).map_err(|e| Error::wrap("Invalid UTF-8 decimal", e))?;
+    parse_dec(value)
+}
+
+/// Converts an integer with `scale` fractional digits into a `Decimal`.
+pub(crate) fn to_decimal(value: u128, scale: u32) -> CosmosResult<Decimal> {
+    let value = i128::try_from(value)
+        .map_err(|_| Error::Custom(format!("Decimal {value} out of range")))?;
+    Decimal::try_from_i128_with_scale(value, scale)
+        .map_err(|_| Error::Custom(format!("Decimal {value} out of range")))
+}
@@ -22,33 +22,101 @@ pub enum Error {
     TonicStatus(#[from] tonic::Status),
     #[error(transparent)]
     TendermintError(#[from] cosmrs::tendermint::Error),
+    #[error(transparent)]
+    IoError(#[from] std::io::Error),
+    #[error(transparent)]
+    JsonError(#[from] serde_json::Error),
 
     #[error("Unknown cosmos-sdk Msg")]
     UnknownCosmosMsg,
     #[error("No signer attached")]
     NoSignerAttached,
+    #[error("Configured chain ID {configured} does not match the node's chain ID {node}")]
+    ChainIdMismatch { configured: String, node: String },
+    #[error("No chain ID configured")]
+    NoChainId,
     #[error("No subscription")]
     NoSubscription,
+    #[error("Invalid subscription query {0:?}")]
+    InvalidSubscriptionQuery(String),
     #[error("Cannot simulate TX Gas")]
     CannotSimulateTxGas,
     #[error("Out of gas")]
     OutOfGas,
+    #[error("Transaction of {size} bytes exceeds the {limit} bytes limit")]
+    TxTooLarge { size: usize, limit: u64 },
+    #[error("Account sequence mismatch: expected {expected}, got {got}")]
+    SequenceMismatch { expected: u64, got: u64 },
+    #[error("Transaction failed with code {code} in codespace {codespace}: {log}")]
+    TxFailed {
+        codespace: String,
+        code: u32,
+        log: String,
+    },
+    #[error("Insufficient funds: needed {needed}, available {available}")]
+    InsufficientFunds { needed: String, available: String },
     #[error("Account does not exist {address:?}")]
     AccountDoesNotExist { address: String },
+    #[error("Address {address} does not have the expected prefix {expected}")]
+    InvalidAddressPrefix { address: String, expected: String },
+    #[error("No fee allowance from {granter} to {grantee}")]
+    NoFeeAllowance { granter: String, grantee: String },
+    #[error("Height {requested} is not available on the node: {message}")]
+    HeightNotAvailable {
+        requested: u64,
+        earliest: Option<u64>,
+        message: String,
+    },
+    #[error("Invalid derivation path {path:?}: {reason}")]
+    InvalidDerivationPath { path: String, reason: String },
+    #[error("Gas adjustment {0} must be a multiplier of at least 1.0")]
+    InvalidGasAdjustment(f64),
+    #[error("Keystore error: {0}")]
+    Keystore(String),
+    #[error("Ledger error: {0}")]
+    Ledger(String),
     #[error("Rpc errors : {0}")]
     RpcError(String),
+    #[error("ABCI query failed with code {code} in codespace {codespace}: {log}")]
+    AbciQueryFailed {
+        codespace: String,
+        code: u32,
+        log: String,
+    },
     #[error("QueryPath errors : {0}")]
     QueryPath(String),
     #[error("NoneTxResponse")]
     NoneTxResponse,
     #[error("TXPollingTimeout")]
     TXPollingTimeout,
+    #[error("Request timed out after {0:?}")]
+    Timeout(std::time::Duration),
     #[error("No base account for vesting wallet")]
     NoVestingBaseAccount,
     #[error("{0}")]
     Custom(String),
+    #[error("{message}")]
+    Wrapped {
+        message: String,
+        #[source]
+        source: Box<dyn std::error::Error + Send + Sync>,
+    },
 
     #[cfg(feature = "osmosis")]
     #[error("Not found pool")]
     NotFoundPool,
 }
+
+impl Error {
+    /// Wraps an arbitrary error with a message, keeping it reachable through
+    /// `std::error::Error::source()`.
+    pub fn wrap<E>(message: impl Into<String>, source: E) -> Self
+    where
+        E: Into<Box<dyn std::error::Error + Send + Sync>>,
+    {
+        Error::Wrapped {
+            message: message.into(),
+            source: source.into(),
+        }
+    }
+}
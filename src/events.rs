@@ -0,0 +1,108 @@
+use std::collections::HashMap;
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use cosmrs::proto::cosmos::tx::v1beta1::GetTxResponse;
+use cosmrs::tendermint::abci;
+
+use crate::error::{CosmosResult, Error};
+
+/// An ABCI event emitted while executing a transaction, with its attributes decoded to
+/// strings.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Event {
+    /// Type of the event, e.g. `transfer` or `token_swapped`.
+    pub kind: String,
+    /// Attributes of the event. If a key is repeated, the first value is kept.
+    pub attributes: HashMap<String, String>,
+}
+
+impl Event {
+    /// Returns the value of an attribute of the event.
+    pub fn attribute(&self, key: &str) -> Option<&str> {
+        self.attributes.get(key).map(String::as_str)
+    }
+}
+
+impl From<&abci::Event> for Event {
+    /// Decodes the attributes, including base64-encoded ones emitted by Tendermint v0.34 based
+    /// chains.
+    fn from(event: &abci::Event) -> Self {
+        let base64_encoded = !event.attributes.is_empty()
+            && event
+                .attributes
+                .iter()
+                .all(|attr| decode_base64_key(attr.key_bytes()).is_some());
+
+        let mut attributes = HashMap::new();
+        for attr in &event.attributes {
+            let (key, value) = if base64_encoded {
+                (
+                    decode_base64_key(attr.key_bytes()).unwrap_or_default(),
+                    decode_base64_value(attr.value_bytes()),
+                )
+            } else {
+                (
+                    String::from_utf8_lossy(attr.key_bytes()).into_owned(),
+                    String::from_utf8_lossy(attr.value_bytes()).into_owned(),
+                )
+            };
+            attributes.entry(key).or_insert(value);
+        }
+
+        Event {
+            kind: event.kind.clone(),
+            attributes,
+        }
+    }
+}
+
+/// Parses ABCI events, e.g. from a block result or a subscription.
+pub fn parse_events(events: &[abci::Event]) -> Vec<Event> {
+    events.iter().map(Event::from).collect()
+}
+
+/// Parses the events of a confirmed transaction.
+///
+/// # Errors
+///
+/// * Returns `Error::NoneTxResponse` if the response does not contain the transaction result.
+pub fn tx_events(resp: &GetTxResponse) -> CosmosResult<Vec<Event>> {
+    let tx_resp = resp.tx_response.as_ref().ok_or(Error::NoneTxResponse)?;
+
+    tx_resp
+        .events
+        .iter()
+        .map(|event| Ok(Event::from(&abci::Event::try_from(event.clone())?)))
+        .collect()
+}
+
+/// Returns the value of the first attribute named `key` of the first event of `event_type`
+/// holding it, e.g. `find_attribute(&events, "token_swapped", "tokens_out")`.
+pub fn find_attribute<'a>(events: &'a [Event], event_type: &str, key: &str) -> Option<&'a str> {
+    events
+        .iter()
+        .filter(|event| event.kind == event_type)
+        .find_map(|event| event.attribute(key))
+}
+
+/// Decodes a base64-encoded attribute key, returning `None` if it is not base64 or does not
+/// decode to an identifier, as keys always are.
+fn decode_base64_key(key: &[u8]) -> Option<String> {
+    let decoded = String::from_utf8(STANDARD.decode(key).ok()?).ok()?;
+    let is_identifier = !decoded.is_empty()
+        && decoded
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '-'));
+
+    is_identifier.then_some(decoded)
+}
+
+/// Decodes a base64-encoded attribute value, keeping it as is if it does not decode to UTF-8.
+fn decode_base64_value(value: &[u8]) -> String {
+    STANDARD
+        .decode(value)
+        .ok()
+        .and_then(|decoded| String::from_utf8(decoded).ok())
+        .unwrap_or_else(|| String::from_utf8_lossy(value).into_owned())
+}
@@ -1,8 +1,13 @@
 pub mod client;
+pub mod clock;
 pub mod cosmos;
+mod decimal;
 pub mod error;
+pub mod events;
 #[cfg(feature = "osmosis")]
 pub mod osmosis;
+pub mod pagination;
+pub mod registry;
 pub mod rpc;
 pub mod signer;
 pub mod tx;
@@ -0,0 +1,49 @@
+use osmosis_std::types::osmosis::epochs::v1beta1::{
+    EpochInfo, QueryCurrentEpochRequest, QueryCurrentEpochResponse, QueryEpochsInfoRequest,
+    QueryEpochsInfoResponse,
+};
+
+use crate::error::{CosmosResult, Error};
+use crate::{client::CosmosClient, rpc::types::Rpc};
+
+/// A struct representing a client to interact with the Osmosis epochs module.
+#[derive(Debug, Clone)]
+pub struct Epochs<T: Rpc + Clone + Send + Sync> {
+    client: CosmosClient<T>,
+}
+
+impl<T: Rpc + Clone + Send + Sync> Epochs<T> {
+    /// Creates a new Epochs with the provided CosmosClient.
+    pub fn new(client: CosmosClient<T>) -> Self {
+        Self { client }
+    }
+
+    /// Retrieves all running epochs, including their current number, duration and start time.
+    pub async fn epochs_info(&self) -> CosmosResult<QueryEpochsInfoResponse> {
+        let query = QueryEpochsInfoRequest {};
+        self.client
+            .query("/osmosis.epochs.v1beta1.Query/EpochInfos", query)
+            .await
+    }
+
+    /// Retrieves the current epoch number for the given identifier (e.g. `"day"`, `"week"`).
+    pub async fn current_epoch(&self, identifier: &str) -> CosmosResult<QueryCurrentEpochResponse> {
+        let query = QueryCurrentEpochRequest {
+            identifier: identifier.to_string(),
+        };
+        self.client
+            .query("/osmosis.epochs.v1beta1.Query/CurrentEpoch", query)
+            .await
+    }
+
+    /// Retrieves the full information of a single epoch, including the start time of its
+    /// current period.
+    pub async fn epoch(&self, identifier: &str) -> CosmosResult<EpochInfo> {
+        self.epochs_info()
+            .await?
+            .epochs
+            .into_iter()
+            .find(|epoch| epoch.identifier == identifier)
+            .ok_or_else(|| Error::Custom(format!("Epoch {identifier} not found")))
+    }
+}
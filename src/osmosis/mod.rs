@@ -1,2 +1,3 @@
 pub mod concentratedliquidity;
+pub mod epochs;
 pub mod poolmanager;
@@ -1,9 +1,15 @@
+use cosmrs::proto::cosmos::base::v1beta1::Coin;
 use cosmrs::proto::prost::Message;
+use cosmrs::tx::Body;
+use cosmrs::Any;
+use osmosis_std::types::cosmos::base::v1beta1::Coin as OsmosisCoin;
 use osmosis_std::types::osmosis::concentratedliquidity::v1beta1::Pool as ConcentratedAmm;
 use osmosis_std::types::osmosis::cosmwasmpool::v1beta1::CosmWasmPool;
 use osmosis_std::types::osmosis::gamm::v1beta1::Pool as Amm;
 use osmosis_std::types::osmosis::poolmanager::v1beta1::{
-    PoolRequest, PoolResponse, SpotPriceRequest,
+    EstimateSwapExactAmountInRequest, EstimateSwapExactAmountInResponse, MsgSwapExactAmountIn,
+    MsgSwapExactAmountOut, PoolRequest, PoolResponse, SpotPriceRequest, SwapAmountInRoute,
+    SwapAmountOutRoute,
 };
 use osmosis_std::types::osmosis::poolmanager::v2::SpotPriceResponse;
 
@@ -18,6 +24,26 @@ pub enum Pool {
     CosmWasmPool(CosmWasmPool),
 }
 
+impl Pool {
+    /// Returns the id of the pool.
+    pub fn pool_id(&self) -> u64 {
+        match self {
+            Pool::Amm(pool) => pool.id,
+            Pool::ConcentratedAmm(pool) => pool.id,
+            Pool::CosmWasmPool(pool) => pool.pool_id,
+        }
+    }
+
+    /// Returns the name of the pool type.
+    pub fn pool_type(&self) -> &'static str {
+        match self {
+            Pool::Amm(_) => "Balancer",
+            Pool::ConcentratedAmm(_) => "Concentrated",
+            Pool::CosmWasmPool(_) => "CosmWasm",
+        }
+    }
+}
+
 /// A struct representing a client to interact with the Osmosis Pool Manager.
 #[derive(Debug, Clone)]
 pub struct PoolManager<T: Rpc + Clone + Send + Sync> {
@@ -57,6 +83,107 @@ impl<T: Rpc + Clone + Send + Sync> PoolManager<T> {
             .await
     }
 
+    /// Estimates the output of swapping an exact input amount along a route, before
+    /// broadcasting the swap.
+    ///
+    /// # Arguments
+    ///
+    /// * `token_in`: The tokens to swap.
+    /// * `routes`: The pools to swap through, in order.
+    pub async fn estimate_swap_exact_amount_in(
+        &self,
+        token_in: Coin,
+        routes: Vec<SwapAmountInRoute>,
+    ) -> CosmosResult<EstimateSwapExactAmountInResponse> {
+        let query = EstimateSwapExactAmountInRequest {
+            token_in: format!("{}{}", token_in.amount, token_in.denom),
+            routes,
+            ..Default::default()
+        };
+        self.client
+            .query(
+                "/osmosis.poolmanager.v1beta1.Query/EstimateSwapExactAmountIn",
+                query,
+            )
+            .await
+    }
+
+    /// Builds a swap of an exact input amount along a route, with the attached signer as
+    /// sender.
+    ///
+    /// # Arguments
+    ///
+    /// * `routes`: The pools to swap through, in order.
+    /// * `token_in`: The tokens to swap.
+    /// * `token_out_min_amount`: The minimum output amount, below which the swap fails.
+    /// * `memo`: An optional memo to attach to the transaction.
+    /// * `timeout_height`: The height after which the transaction can no longer be included. If
+    ///   `None`, the client's timeout height offset applies at signing time, if configured.
+    ///
+    /// # Errors
+    ///
+    /// * Returns `Error::NoSignerAttached` if no signer is attached to the client.
+    pub async fn swap_exact_amount_in(
+        &self,
+        routes: Vec<SwapAmountInRoute>,
+        token_in: Coin,
+        token_out_min_amount: &str,
+        memo: Option<&str>,
+        timeout_height: Option<u64>,
+    ) -> CosmosResult<Body> {
+        let signer = self.client.signer().ok_or(Error::NoSignerAttached)?;
+        let msg = MsgSwapExactAmountIn {
+            sender: signer.public_address.to_string(),
+            routes,
+            token_in: Some(to_osmosis_coin(token_in)),
+            token_out_min_amount: token_out_min_amount.to_string(),
+        };
+        let msg = Any {
+            type_url: MsgSwapExactAmountIn::TYPE_URL.to_string(),
+            value: msg.encode_to_vec(),
+        };
+
+        self.client.build_tx(vec![msg], memo, timeout_height)
+    }
+
+    /// Builds a swap for an exact output amount along a route, with the attached signer as
+    /// sender.
+    ///
+    /// # Arguments
+    ///
+    /// * `routes`: The pools to swap through, in order.
+    /// * `token_in_max_amount`: The maximum input amount, above which the swap fails.
+    /// * `token_out`: The tokens to receive.
+    /// * `memo`: An optional memo to attach to the transaction.
+    /// * `timeout_height`: The height after which the transaction can no longer be included. If
+    ///   `None`, the client's timeout height offset applies at signing time, if configured.
+    ///
+    /// # Errors
+    ///
+    /// * Returns `Error::NoSignerAttached` if no signer is attached to the client.
+    pub async fn swap_exact_amount_out(
+        &self,
+        routes: Vec<SwapAmountOutRoute>,
+        token_in_max_amount: &str,
+        token_out: Coin,
+        memo: Option<&str>,
+        timeout_height: Option<u64>,
+    ) -> CosmosResult<Body> {
+        let signer = self.client.signer().ok_or(Error::NoSignerAttached)?;
+        let msg = MsgSwapExactAmountOut {
+            sender: signer.public_address.to_string(),
+            routes,
+            token_in_max_amount: token_in_max_amount.to_string(),
+            token_out: Some(to_osmosis_coin(token_out)),
+        };
+        let msg = Any {
+            type_url: MsgSwapExactAmountOut::TYPE_URL.to_string(),
+            value: msg.encode_to_vec(),
+        };
+
+        self.client.build_tx(vec![msg], memo, timeout_height)
+    }
+
     /// Retrieves information about a specific pool.
     ///
     /// # Arguments
@@ -91,3 +218,11 @@ impl<T: Rpc + Clone + Send + Sync> PoolManager<T> {
         }
     }
 }
+
+/// Converts a coin to the osmosis-std coin type.
+fn to_osmosis_coin(coin: Coin) -> OsmosisCoin {
+    OsmosisCoin {
+        denom: coin.denom,
+        amount: coin.amount,
+    }
+}
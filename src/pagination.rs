@@ -0,0 +1,119 @@
+use std::collections::HashSet;
+use std::future::Future;
+
+use cosmrs::proto::cosmos::base::query::v1beta1::{PageRequest, PageResponse};
+use futures::stream::{self, Stream};
+
+use crate::error::{CosmosResult, Error};
+
+/// Repeatedly issues a paged query, following `pagination.next_key` until the last page, and
+/// collects the items of every page.
+///
+/// # Arguments
+///
+/// * `fetch` - Issues the query for a page, e.g. `|page| auth.accounts(Some(page))`.
+/// * `extract` - Splits a response into its items and pagination, e.g.
+///   `|resp| (resp.accounts, resp.pagination)`.
+///
+/// # Errors
+///
+/// * Returns `Error::Custom` if the node returns a `next_key` it already returned, which would
+///   otherwise loop forever.
+pub async fn paginate_all<Resp, Item, F, Fut, E>(fetch: F, extract: E) -> CosmosResult<Vec<Item>>
+where
+    F: FnMut(PageRequest) -> Fut,
+    Fut: Future<Output = CosmosResult<Resp>>,
+    E: FnMut(Resp) -> (Vec<Item>, Option<PageResponse>),
+{
+    let mut pages = Pages::new(fetch, extract);
+    let mut items = Vec::new();
+    while let Some(page) = pages.next_page().await? {
+        items.extend(page);
+    }
+
+    Ok(items)
+}
+
+/// Streams the items of a paged query, fetching the next page once the current one is
+/// consumed. See `paginate_all` for the arguments.
+pub fn paginate_stream<Resp, Item, F, Fut, E>(
+    fetch: F,
+    extract: E,
+) -> impl Stream<Item = CosmosResult<Item>>
+where
+    F: FnMut(PageRequest) -> Fut,
+    Fut: Future<Output = CosmosResult<Resp>>,
+    E: FnMut(Resp) -> (Vec<Item>, Option<PageResponse>),
+{
+    let pages = Pages::new(fetch, extract);
+    stream::unfold(
+        (pages, Vec::new().into_iter()),
+        |(mut pages, mut page)| async move {
+            loop {
+                if let Some(item) = page.next() {
+                    return Some((Ok(item), (pages, page)));
+                }
+
+                match pages.next_page().await {
+                    Ok(Some(next)) => page = next.into_iter(),
+                    Ok(None) => return None,
+                    Err(e) => {
+                        pages.done = true;
+                        return Some((Err(e), (pages, page)));
+                    }
+                }
+            }
+        },
+    )
+}
+
+/// Cursor over the pages of a paged query.
+struct Pages<F, E> {
+    fetch: F,
+    extract: E,
+    next_key: Vec<u8>,
+    seen_keys: HashSet<Vec<u8>>,
+    done: bool,
+}
+
+impl<F, E> Pages<F, E> {
+    fn new(fetch: F, extract: E) -> Self {
+        Self {
+            fetch,
+            extract,
+            next_key: Vec::new(),
+            seen_keys: HashSet::new(),
+            done: false,
+        }
+    }
+
+    /// Fetches the next page, or returns `None` after the last one.
+    async fn next_page<Resp, Item, Fut>(&mut self) -> CosmosResult<Option<Vec<Item>>>
+    where
+        F: FnMut(PageRequest) -> Fut,
+        Fut: Future<Output = CosmosResult<Resp>>,
+        E: FnMut(Resp) -> (Vec<Item>, Option<PageResponse>),
+    {
+        if self.done {
+            return Ok(None);
+        }
+
+        let page = PageRequest {
+            key: std::mem::take(&mut self.next_key),
+            ..Default::default()
+        };
+        let (items, pagination) = (self.extract)((self.fetch)(page).await?);
+
+        self.next_key = pagination.map(|p| p.next_key).unwrap_or_default();
+        if self.next_key.is_empty() {
+            self.done = true;
+        } else if !self.seen_keys.insert(self.next_key.clone()) {
+            self.done = true;
+            return Err(Error::Custom(
+                "Pagination returned an already visited next_key".to_string(),
+            ));
+        }
+
+        Ok(Some(items))
+    }
+}
@@ -0,0 +1,106 @@
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use cosmrs::proto::cosmos::bank::v1beta1::{MsgMultiSend, MsgSend};
+use cosmrs::proto::cosmos::distribution::v1beta1::{
+    MsgSetWithdrawAddress, MsgWithdrawDelegatorReward, MsgWithdrawValidatorCommission,
+};
+use cosmrs::proto::cosmos::gov::v1beta1::{MsgDeposit, MsgVote};
+use cosmrs::proto::cosmos::staking::v1beta1::{MsgBeginRedelegate, MsgDelegate, MsgUndelegate};
+use cosmrs::proto::ibc::applications::transfer::v1::MsgTransfer;
+use cosmrs::proto::prost::Message;
+use cosmrs::Any;
+
+use crate::error::{CosmosResult, Error};
+
+/// A decoded message of any registered type.
+pub type DecodedMsg = Box<dyn Debug + Send + Sync>;
+
+/// Function decoding the protobuf value of an `Any` into a registered message type.
+pub type AnyDecoder = Arc<dyn Fn(&[u8]) -> CosmosResult<DecodedMsg> + Send + Sync>;
+
+/// Registry mapping `Any` type URLs to decoders, used to name and decode messages found in
+/// transactions. Chain-specific messages can be added with `register`.
+#[derive(Clone, Default)]
+pub struct MessageRegistry {
+    decoders: HashMap<String, AnyDecoder>,
+}
+
+impl MessageRegistry {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a registry knowing the standard cosmos-sdk and IBC transfer messages.
+    pub fn with_defaults() -> Self {
+        let mut registry = Self::new();
+        registry.register::<MsgSend>("/cosmos.bank.v1beta1.MsgSend");
+        registry.register::<MsgMultiSend>("/cosmos.bank.v1beta1.MsgMultiSend");
+        registry.register::<MsgDelegate>("/cosmos.staking.v1beta1.MsgDelegate");
+        registry.register::<MsgUndelegate>("/cosmos.staking.v1beta1.MsgUndelegate");
+        registry.register::<MsgBeginRedelegate>("/cosmos.staking.v1beta1.MsgBeginRedelegate");
+        registry.register::<MsgWithdrawDelegatorReward>(
+            "/cosmos.distribution.v1beta1.MsgWithdrawDelegatorReward",
+        );
+        registry.register::<MsgWithdrawValidatorCommission>(
+            "/cosmos.distribution.v1beta1.MsgWithdrawValidatorCommission",
+        );
+        registry.register::<MsgSetWithdrawAddress>(
+            "/cosmos.distribution.v1beta1.MsgSetWithdrawAddress",
+        );
+        registry.register::<MsgVote>("/cosmos.gov.v1beta1.MsgVote");
+        registry.register::<MsgDeposit>("/cosmos.gov.v1beta1.MsgDeposit");
+        registry.register::<MsgTransfer>("/ibc.applications.transfer.v1.MsgTransfer");
+        registry
+    }
+
+    /// Registers a message type under its type URL, e.g. `"/osmosis.gamm.v1beta1.MsgSwapExactAmountIn"`.
+    pub fn register<M>(&mut self, type_url: &str)
+    where
+        M: Message + Default + Debug + Send + Sync + 'static,
+    {
+        let decoder: AnyDecoder =
+            Arc::new(|bytes: &[u8]| Ok(Box::new(M::decode(bytes)?) as DecodedMsg));
+        self.register_decoder(type_url, decoder);
+    }
+
+    /// Registers a custom decoder under a type URL.
+    pub fn register_decoder(&mut self, type_url: &str, decoder: AnyDecoder) {
+        self.decoders.insert(type_url.to_string(), decoder);
+    }
+
+    /// Returns whether a decoder is registered for the type URL.
+    pub fn is_registered(&self, type_url: &str) -> bool {
+        self.decoders.contains_key(type_url)
+    }
+
+    /// Returns the fully qualified message name of a registered `Any`, e.g.
+    /// `"cosmos.bank.v1beta1.MsgSend"`.
+    pub fn name<'a>(&self, any: &'a Any) -> Option<&'a str> {
+        self.is_registered(&any.type_url)
+            .then(|| any.type_url.trim_start_matches('/'))
+    }
+
+    /// Decodes an `Any` with the decoder registered for its type URL.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::UnknownCosmosMsg` if no decoder is registered for the type URL.
+    pub fn decode_any(&self, any: &Any) -> CosmosResult<DecodedMsg> {
+        let decoder = self
+            .decoders
+            .get(&any.type_url)
+            .ok_or(Error::UnknownCosmosMsg)?;
+        decoder(&any.value)
+    }
+}
+
+impl Debug for MessageRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MessageRegistry")
+            .field("type_urls", &self.decoders.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
@@ -1,39 +1,282 @@
 use std::str::FromStr;
+use std::time::Duration;
 
 use async_trait::async_trait;
 use bytes::Bytes;
-use cosmrs::proto::cosmos::tx::v1beta1::service_client::ServiceClient;
-use cosmrs::proto::cosmos::tx::v1beta1::{BroadcastMode, BroadcastTxRequest};
+use cosmrs::proto::cosmos::base::abci::v1beta1::TxResponse;
+use cosmrs::proto::cosmos::tx::v1beta1::{BroadcastMode, BroadcastTxRequest, BroadcastTxResponse};
 use cosmrs::proto::cosmos::tx::v1beta1::{SimulateRequest, SimulateResponse};
 use cosmrs::proto::prost::Message;
 
 use cosmrs::tendermint::abci::Code;
 use cosmrs::tendermint::Hash;
 use tonic::codec::ProstCodec;
-use tonic::transport::Channel;
+use tonic::metadata::MetadataValue;
+use tonic::transport::{Channel, Endpoint};
 
 use crate::error::{CosmosResult, Error};
-use crate::rpc::types::{Rpc, TxAsyncResponse, TxSyncResponse};
+use crate::rpc::retry::{with_retry_reporting, with_timeout, RetryConfig};
+use crate::rpc::types::{
+    is_duplicate_tx, map_height_error, tx_hash, Rpc, TxAsyncResponse, TxSyncResponse,
+};
+
+/// Metadata header selecting the block height a Cosmos gRPC query is served at.
+const BLOCK_HEIGHT_HEADER: &str = "x-cosmos-block-height";
+
+/// HTTP/1.1 client translating gRPC calls to gRPC-web requests.
+#[cfg(feature = "grpc-web")]
+type GrpcWebService = tonic_web::GrpcWebClientService<
+    hyper_util::client::legacy::Client<
+        hyper_rustls::HttpsConnector<hyper_util::client::legacy::connect::HttpConnector>,
+        tonic_web::GrpcWebCall<tonic::body::BoxBody>,
+    >,
+>;
+
+/// Transport carrying the calls of a `Grpc` client.
+#[derive(Clone)]
+enum Transport {
+    /// Native gRPC over an HTTP/2 channel, connected at construction.
+    Channel(tonic::client::Grpc<Channel>),
+    /// gRPC-web over HTTP/1.1, as served by browser-facing proxies.
+    #[cfg(feature = "grpc-web")]
+    Web(tonic::client::Grpc<GrpcWebService>),
+}
+
+impl std::fmt::Debug for Transport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Transport::Channel(_) => f.write_str("Channel"),
+            #[cfg(feature = "grpc-web")]
+            Transport::Web(_) => f.write_str("Web"),
+        }
+    }
+}
 
 /// Struct representing a gRPC client for interacting with Cosmos blockchain.
 #[derive(Clone, Debug)]
 pub struct Grpc {
+    /// Endpoint the client was created with, kept for display only.
     grpc_endpoint: String,
-    client: tonic::client::Grpc<Channel>,
+    /// Prefix prepended to the method path of every call, without a trailing `/`.
+    path_prefix: String,
+    /// Transport shared by queries, simulations and broadcasts, so every call reuses the same
+    /// connections.
+    transport: Transport,
+    retry: Option<RetryConfig>,
+    timeout: Option<Duration>,
 }
 
 impl Grpc {
     /// Creates a new `Grpc` instance with the specified gRPC endpoint.
     pub async fn new(grpc_endpoint: &str) -> CosmosResult<Self> {
-        let conn = tonic::transport::Endpoint::new(grpc_endpoint.to_string())?
-            .connect()
-            .await?;
+        let endpoint = Endpoint::new(grpc_endpoint.to_string())?;
+        Self::connect(endpoint, grpc_endpoint).await
+    }
+
+    /// Creates a new `Grpc` instance for an endpoint whose gRPC services are routed under a
+    /// path prefix, e.g. a reverse proxy serving them at `https://host/cosmos-grpc`.
+    ///
+    /// See `set_path_prefix`.
+    pub async fn new_with_path_prefix(
+        grpc_endpoint: &str,
+        path_prefix: &str,
+    ) -> CosmosResult<Self> {
+        let mut grpc = Self::new(grpc_endpoint).await?;
+        grpc.set_path_prefix(path_prefix);
+        Ok(grpc)
+    }
+
+    /// Creates a new `Grpc` instance that fails if the connection to the endpoint cannot be
+    /// established within `connect_timeout`, instead of waiting indefinitely.
+    ///
+    /// This only bounds connecting; use `set_timeout` to also bound each call.
+    pub async fn new_with_timeout(
+        grpc_endpoint: &str,
+        connect_timeout: Duration,
+    ) -> CosmosResult<Self> {
+        let endpoint = Endpoint::new(grpc_endpoint.to_string())?.connect_timeout(connect_timeout);
+        Self::connect(endpoint, grpc_endpoint).await
+    }
+
+    /// Connects to a configured endpoint.
+    async fn connect(endpoint: Endpoint, grpc_endpoint: &str) -> CosmosResult<Self> {
+        let conn = endpoint.connect().await?;
         let client = tonic::client::Grpc::new(conn);
 
-        Ok(Self {
-            client,
+        Ok(Self::with_transport(
+            Transport::Channel(client),
+            grpc_endpoint,
+        ))
+    }
+
+    /// Creates a new `Grpc` instance talking gRPC-web over HTTP/1.1 to the specified endpoint,
+    /// e.g. a proxy such as Envoy exposing the node's services to browsers.
+    ///
+    /// No connection is opened until the first call. Endpoints routed under a path are
+    /// configured with `set_path_prefix`.
+    #[cfg(feature = "grpc-web")]
+    pub fn new_grpc_web(grpc_web_endpoint: &str) -> CosmosResult<Self> {
+        use tower::Layer;
+
+        let origin = grpc_web_endpoint
+            .parse::<tonic::codegen::http::Uri>()
+            .map_err(|e| {
+                Error::wrap(format!("Invalid gRPC-web endpoint {grpc_web_endpoint}"), e)
+            })?;
+        let connector = hyper_rustls::HttpsConnectorBuilder::new()
+            .with_webpki_roots()
+            .https_or_http()
+            .enable_http1()
+            .build();
+        let http =
+            hyper_util::client::legacy::Client::builder(hyper_util::rt::TokioExecutor::new())
+                .build(connector);
+        let service = tonic_web::GrpcWebClientLayer::new().layer(http);
+        let client = tonic::client::Grpc::with_origin(service, origin);
+
+        Ok(Self::with_transport(
+            Transport::Web(client),
+            grpc_web_endpoint,
+        ))
+    }
+
+    /// Creates a client with default settings over the given transport.
+    fn with_transport(transport: Transport, grpc_endpoint: &str) -> Self {
+        Self {
+            transport,
             grpc_endpoint: grpc_endpoint.to_string(),
+            path_prefix: String::new(),
+            retry: None,
+            timeout: None,
+        }
+    }
+
+    /// Sets the path prefix prepended to the method path of every call, e.g. `/cosmos-grpc`
+    /// for a proxy routing `/cosmos-grpc/cosmos.bank.v1beta1.Query/Balance` to the node.
+    /// Passing `""` removes it.
+    ///
+    /// The transport only keeps the scheme and authority of the endpoint, so the prefix must be
+    /// set here rather than in the endpoint URL. This applies to native gRPC and gRPC-web
+    /// endpoints alike.
+    pub fn set_path_prefix(&mut self, path_prefix: &str) {
+        self.path_prefix = path_prefix.trim_end_matches('/').to_string();
+    }
+
+    /// Sets the policy for retrying calls that fail with a transient network error. Passing
+    /// `None` disables retries, the default.
+    pub fn set_retry(&mut self, retry: Option<RetryConfig>) {
+        self.retry = retry;
+    }
+
+    /// Sets the deadline of each call, after which it fails with `Error::Timeout`. Passing
+    /// `None` removes the deadline.
+    pub fn set_timeout(&mut self, timeout: Option<Duration>) {
+        self.timeout = timeout;
+    }
+
+    /// Performs a unary call at the given path, optionally targeting a past block height, and
+    /// retries it according to the retry policy.
+    async fn unary<M, R>(&self, path: &str, msg: M, height: Option<u64>) -> CosmosResult<R>
+    where
+        M: Message + Default + 'static,
+        R: Message + Default + 'static,
+    {
+        self.unary_reporting(path, msg, height).await.0
+    }
+
+    /// Like `unary`, also reporting whether the result comes from a retry.
+    async fn unary_reporting<M, R>(
+        &self,
+        path: &str,
+        msg: M,
+        height: Option<u64>,
+    ) -> (CosmosResult<R>, bool)
+    where
+        M: Message + Default + 'static,
+        R: Message + Default + 'static,
+    {
+        let data = &msg.encode_to_vec();
+        with_retry_reporting(self.retry.as_ref(), move || async move {
+            let msg = M::decode(data.as_slice())?;
+            with_timeout(self.timeout, self.unary_once(path, msg, height)).await
         })
+        .await
+    }
+
+    /// Performs a single unary call attempt.
+    async fn unary_once<M, R>(&self, path: &str, msg: M, height: Option<u64>) -> CosmosResult<R>
+    where
+        M: Message + Default + 'static,
+        R: Message + Default + 'static,
+    {
+        let codec: ProstCodec<M, R> = tonic::codec::ProstCodec::default();
+        let mut request = tonic::Request::new(msg);
+        if let Some(height) = height {
+            request
+                .metadata_mut()
+                .insert(BLOCK_HEIGHT_HEADER, MetadataValue::from(height));
+        }
+
+        let full_path = format!("{}{}", self.path_prefix, path);
+        let path = full_path
+            .parse()
+            .map_err(|_| Error::QueryPath(full_path.clone()))?;
+        let res = match &self.transport {
+            Transport::Channel(client) => {
+                let mut client = client.clone();
+                client.ready().await?;
+                client.unary(request, path, codec).await?
+            }
+            #[cfg(feature = "grpc-web")]
+            Transport::Web(client) => {
+                let mut client = client.clone();
+                client
+                    .ready()
+                    .await
+                    .map_err(|e| Error::wrap("gRPC-web transport unavailable", e))?;
+                client.unary(request, path, codec).await?
+            }
+        };
+
+        Ok(res.into_inner())
+    }
+
+    /// Broadcasts a transaction in the given mode.
+    ///
+    /// If a retried broadcast finds the transaction already in the node's mempool cache, an
+    /// earlier attempt was accepted, and the broadcast succeeds with the transaction's hash.
+    async fn broadcast(
+        &self,
+        payload: Vec<u8>,
+        mode: BroadcastMode,
+    ) -> CosmosResult<TxSyncResponse> {
+        let hash = tx_hash(&payload);
+        let request = BroadcastTxRequest {
+            tx_bytes: payload,
+            mode: mode.into(),
+        };
+
+        let (res, retried) = self
+            .unary_reporting::<_, BroadcastTxResponse>(
+                "/cosmos.tx.v1beta1.Service/BroadcastTx",
+                request,
+                None,
+            )
+            .await;
+        let mut resp = broadcast_response(res?.tx_response.ok_or(Error::NoneTxResponse)?)?;
+        if retried && is_duplicate_tx(&resp.codespace, resp.code.value()) {
+            resp.code = Code::Ok;
+            resp.hash = hash;
+        }
+
+        Ok(resp)
+    }
+
+    /// Returns the endpoint this client was created with.
+    ///
+    /// Calls never reconnect to it; they all go through the transport created at construction.
+    pub fn endpoint(&self) -> &str {
+        &self.grpc_endpoint
     }
 }
 
@@ -47,79 +290,117 @@ impl Rpc for Grpc {
         M: Message + Default + 'static,
         R: Message + Default + 'static,
     {
-        let codec: ProstCodec<M, R> = tonic::codec::ProstCodec::default();
-        let mut client_clone = self.client.clone();
-        client_clone.ready().await?;
-
-        let res = client_clone
-            .unary(
-                tonic::Request::new(msg),
-                path.parse()
-                    .map_err(|_| Error::QueryPath(path.to_string()))?,
-                codec,
-            )
-            .await?;
+        self.unary(path, msg, None).await
+    }
 
-        Ok(res.into_inner())
+    /// Asynchronously queries the blockchain state at a past block height, passed to the node
+    /// through the `x-cosmos-block-height` metadata header.
+    ///
+    /// The node must still retain the height within its pruning window; otherwise the query
+    /// fails with `Error::HeightNotAvailable`.
+    /// Returns the result as a CosmosResult.
+    async fn query_at_height<M, R>(&self, path: &str, msg: M, height: u64) -> CosmosResult<R>
+    where
+        Self: Sized,
+        M: Message + Default + 'static,
+        R: Message + Default + 'static,
+    {
+        self.unary(path, msg, Some(height))
+            .await
+            .map_err(|e| map_height_error(height, e))
     }
 
     /// Asynchronously simulates a transaction using the provided payload.
     /// Returns the simulation response as a CosmosResult.
     async fn simulate_tx(&self, payload: Vec<u8>) -> CosmosResult<SimulateResponse> {
-        let mut client = ServiceClient::connect(self.grpc_endpoint.clone()).await?;
-
         #[allow(deprecated)]
         let request = SimulateRequest {
             tx: None,
             tx_bytes: payload,
         };
 
-        let res = client.simulate(request).await?;
-
-        Ok(res.into_inner())
+        self.query("/cosmos.tx.v1beta1.Service/Simulate", request)
+            .await
     }
 
     /// Asynchronously broadcasts a transaction without waiting for it to be included in a block.
     /// Returns the async response as a CosmosResult.
     async fn broadcast_tx_async(&self, payload: Vec<u8>) -> CosmosResult<TxAsyncResponse> {
-        let mut client = ServiceClient::connect(self.grpc_endpoint.clone()).await?;
-
-        let request = BroadcastTxRequest {
-            tx_bytes: payload,
-            mode: BroadcastMode::Async.into(),
-        };
-
-        let res = client.broadcast_tx(request).await?;
-        let tx = res.into_inner().tx_response.ok_or(Error::NoneTxResponse)?;
+        let resp = self.broadcast(payload, BroadcastMode::Async).await?;
 
         Ok(TxAsyncResponse {
-            codespace: tx.codespace,
-            code: Code::from(tx.code),
-            data: Bytes::from(tx.data.encode_to_vec()),
-            log: tx.raw_log,
-            hash: Hash::from_str(&tx.txhash).unwrap(),
+            codespace: resp.codespace,
+            code: resp.code,
+            data: resp.data,
+            log: resp.log,
+            hash: resp.hash,
         })
     }
 
     /// Asynchronously broadcasts a transaction and waits for it to be included in a block.
     /// Returns the sync response as a CosmosResult.
     async fn broadcast_tx_sync(&self, payload: Vec<u8>) -> CosmosResult<TxSyncResponse> {
-        let mut client = ServiceClient::connect(self.grpc_endpoint.clone()).await?;
+        self.broadcast(payload, BroadcastMode::Sync).await
+    }
+}
 
-        let request = BroadcastTxRequest {
-            tx_bytes: payload,
-            mode: BroadcastMode::Sync.into(),
-        };
+/// Converts the `TxResponse` of a gRPC broadcast into the response the JSON-RPC transport
+/// returns for the same transaction.
+///
+/// The gRPC response carries the ABCI data and hash hex encoded, while JSON-RPC returns the
+/// raw data bytes and a parsed hash.
+fn broadcast_response(tx: TxResponse) -> CosmosResult<TxSyncResponse> {
+    let data = hex::decode(&tx.data)
+        .map_err(|e| Error::wrap(format!("Invalid broadcast data {:?}", tx.data), e))?;
+    let hash = Hash::from_str(&tx.txhash)
+        .map_err(|e| Error::wrap(format!("Invalid transaction hash {:?}", tx.txhash), e))?;
 
-        let res = client.broadcast_tx(request).await?;
-        let tx = res.into_inner().tx_response.ok_or(Error::NoneTxResponse)?;
+    Ok(TxSyncResponse {
+        codespace: tx.codespace,
+        code: Code::from(tx.code),
+        data: Bytes::from(data),
+        log: tx.raw_log,
+        hash,
+    })
+}
 
-        Ok(TxSyncResponse {
-            codespace: tx.codespace,
-            code: Code::from(tx.code),
-            data: Bytes::from(tx.data.encode_to_vec()),
-            log: tx.raw_log,
-            hash: Hash::from_str(&tx.txhash).unwrap(),
-        })
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HASH: &str = "9F86D081884C7D659A2FEAA0C55AD015A3BF4F1B2B0B822CD15D6C15B0F00A08";
+
+    fn tx_response(txhash: &str, data: &str) -> TxResponse {
+        TxResponse {
+            codespace: "sdk".to_string(),
+            code: 5,
+            data: data.to_string(),
+            raw_log: "insufficient funds".to_string(),
+            txhash: txhash.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn converts_a_well_formed_response() {
+        let resp = broadcast_response(tx_response(HASH, "0A02")).unwrap();
+        assert_eq!(resp.codespace, "sdk");
+        assert_eq!(resp.code, Code::from(5));
+        assert_eq!(resp.data, Bytes::from_static(&[0x0a, 0x02]));
+        assert_eq!(resp.log, "insufficient funds");
+        assert_eq!(resp.hash, Hash::from_str(HASH).unwrap());
+    }
+
+    #[test]
+    fn rejects_malformed_hashes() {
+        for txhash in ["not a hash", &HASH[..62], &format!("{HASH}00"), "ZZ"] {
+            assert!(broadcast_response(tx_response(txhash, "")).is_err());
+        }
+    }
+
+    #[test]
+    fn rejects_malformed_data() {
+        assert!(broadcast_response(tx_response(HASH, "0A0")).is_err());
+        assert!(broadcast_response(tx_response(HASH, "not hex")).is_err());
     }
 }
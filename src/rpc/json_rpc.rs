@@ -1,24 +1,292 @@
+use std::future::Future;
+use std::str::FromStr;
+use std::time::Duration;
+
 use async_trait::async_trait;
+use bytes::Bytes;
 use cosmrs::proto::cosmos::tx::v1beta1::{SimulateRequest, SimulateResponse};
-use cosmrs::rpc::HttpClient;
+use cosmrs::rpc::endpoint::abci_query::AbciQuery;
+use cosmrs::rpc::event::Event;
+use cosmrs::rpc::query::Query;
+use cosmrs::rpc::{
+    HttpClient, Subscription, SubscriptionClient, WebSocketClient, WebSocketClientUrl,
+};
 use cosmrs::tendermint::abci::Code;
+use cosmrs::tendermint::block::Height;
 use cosmrs::{proto::prost::Message, rpc::Client};
+use futures::stream::{self, Stream, StreamExt};
+use tokio::task::JoinHandle;
 
 use crate::error::{CosmosResult, Error};
-use crate::rpc::types::{Rpc, TxAsyncResponse, TxSyncResponse};
+use crate::rpc::retry::{with_retry, with_retry_reporting, with_timeout, RetryConfig};
+use crate::rpc::types::{
+    is_duplicate_tx, is_duplicate_tx_error, map_height_error, tx_hash, Rpc, TxAsyncResponse,
+    TxSyncResponse,
+};
+
+/// Delay before reconnecting a dropped WebSocket subscription.
+const RECONNECT_DELAY: Duration = Duration::from_secs(1);
+
+/// Options of a raw ABCI query.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AbciQueryOptions {
+    /// Height of the state to query, or `None` for the latest state. The height must be
+    /// within the node's pruning window.
+    pub height: Option<u64>,
+    /// Whether to request a Merkle proof of the returned value.
+    pub prove: bool,
+}
 
 /// Struct representing a JSON-RPC client for interacting with Cosmos blockchain.
 #[derive(Clone, Debug)]
 pub struct JsonRpc {
+    endpoint: String,
     client: HttpClient,
+    retry: Option<RetryConfig>,
+    timeout: Option<Duration>,
 }
 
 impl JsonRpc {
     /// Creates a new `JsonRpc` instance with the specified RPC endpoint.
     pub fn new(rpc_endpoint: &str) -> CosmosResult<Self> {
         Ok(Self {
+            endpoint: rpc_endpoint.to_string(),
             client: HttpClient::new(rpc_endpoint)?,
+            retry: None,
+            timeout: None,
+        })
+    }
+
+    /// Creates a new `JsonRpc` instance whose calls fail with `Error::Timeout` if they do not
+    /// complete within `timeout`, instead of waiting indefinitely.
+    pub fn new_with_timeout(rpc_endpoint: &str, timeout: Duration) -> CosmosResult<Self> {
+        let mut rpc = Self::new(rpc_endpoint)?;
+        rpc.timeout = Some(timeout);
+        Ok(rpc)
+    }
+
+    /// Sets the deadline of each call, after which it fails with `Error::Timeout`. Passing
+    /// `None` removes the deadline.
+    pub fn set_timeout(&mut self, timeout: Option<Duration>) {
+        self.timeout = timeout;
+    }
+
+    /// Sets the policy for retrying calls that fail with a transient network error. Passing
+    /// `None` disables retries, the default.
+    pub fn set_retry(&mut self, retry: Option<RetryConfig>) {
+        self.retry = retry;
+    }
+
+    /// Subscribes to the events matching a Tendermint query, such as
+    /// `tm.event='Tx' AND transfer.recipient='cosmos1...'`, over the node's WebSocket endpoint.
+    ///
+    /// The stream reconnects and resubscribes when the socket drops. Events emitted while
+    /// disconnected are missed, and each failed reconnection attempt yields an error before
+    /// the next one.
+    ///
+    /// # Errors
+    ///
+    /// * Returns `Error::InvalidSubscriptionQuery` if the query cannot be parsed.
+    pub async fn subscribe(
+        &self,
+        query: &str,
+    ) -> CosmosResult<impl Stream<Item = CosmosResult<Event>>> {
+        let query = Query::from_str(query)
+            .map_err(|_| Error::InvalidSubscriptionQuery(query.to_string()))?;
+        let url = websocket_url(&self.endpoint);
+        let conn = WsSubscription::connect(&url, query.clone()).await?;
+
+        Ok(stream::unfold(
+            (url, query, Some(conn)),
+            |(url, query, mut conn)| async move {
+                loop {
+                    let mut current = match conn.take() {
+                        Some(current) => current,
+                        None => {
+                            tokio::time::sleep(RECONNECT_DELAY).await;
+                            match WsSubscription::connect(&url, query.clone()).await {
+                                Ok(current) => current,
+                                Err(e) => return Some((Err(e), (url, query, None))),
+                            }
+                        }
+                    };
+
+                    match current.subscription.next().await {
+                        Some(Ok(event)) => return Some((Ok(event), (url, query, Some(current)))),
+                        Some(Err(_)) | None => current.close(),
+                    }
+                }
+            },
+        ))
+    }
+
+    /// Performs a raw ABCI query, optionally at a past block height and with a Merkle proof of
+    /// the returned value.
+    ///
+    /// Proofs are only produced for store queries, e.g. `/store/bank/key` with a store key as
+    /// `data`; the node ignores `prove` for gRPC service paths. Proofs of state at height `h`
+    /// are checked against the app hash in the header of block `h + 1`.
+    ///
+    /// # Errors
+    ///
+    /// * Returns `Error::HeightNotAvailable` if the node no longer retains the requested height.
+    /// * Returns `Error::AbciQueryFailed` with the node's code and log if the query fails
+    ///   otherwise.
+    pub async fn abci_query_raw(
+        &self,
+        path: &str,
+        data: Vec<u8>,
+        options: AbciQueryOptions,
+    ) -> CosmosResult<AbciQuery> {
+        let height = options.height.map(Height::try_from).transpose()?;
+        let data = &data;
+        let res = with_retry(self.retry.as_ref(), move || async move {
+            with_timeout(self.timeout, async {
+                Ok(self
+                    .client
+                    .abci_query(Some(path.to_string()), data.clone(), height, options.prove)
+                    .await?)
+            })
+            .await
+        })
+        .await?;
+
+        if res.code != Code::Ok {
+            let error = Error::AbciQueryFailed {
+                codespace: res.codespace,
+                code: res.code.value(),
+                log: res.log,
+            };
+            return Err(match options.height {
+                Some(height) => map_height_error(height, error),
+                None => error,
+            });
+        }
+
+        Ok(res)
+    }
+
+    /// Performs an RPC request, bounded by the call deadline and retried according to the retry
+    /// policy, like every other call of the client.
+    async fn request<F, Fut, R>(&self, request: F) -> CosmosResult<R>
+    where
+        F: Fn() -> Fut,
+        Fut: Future<Output = Result<R, cosmrs::rpc::Error>>,
+    {
+        let request = &request;
+        with_retry(self.retry.as_ref(), move || async move {
+            with_timeout(self.timeout, async move { Ok(request().await?) }).await
         })
+        .await
+    }
+
+    /// Performs an ABCI query at the given path, optionally at a past block height.
+    async fn abci_query<M, R>(&self, path: &str, msg: M, height: Option<u64>) -> CosmosResult<R>
+    where
+        M: Message + Default + 'static,
+        R: Message + Default + 'static,
+    {
+        let options = AbciQueryOptions {
+            height,
+            prove: false,
+        };
+        let res = self
+            .abci_query_raw(path, msg.encode_to_vec(), options)
+            .await?;
+
+        Ok(R::decode(res.value.as_slice())?)
+    }
+
+    /// Computes the ratio of gas used to the block gas limit over the last `blocks` blocks.
+    ///
+    /// Returns an error if the chain does not limit block gas (`max_gas` of `-1`).
+    pub async fn recent_gas_utilization(&self, blocks: u64) -> CosmosResult<f64> {
+        let latest = self
+            .request(|| self.client.latest_block())
+            .await?
+            .block
+            .header
+            .height;
+        let params = self
+            .request(|| self.client.consensus_params(latest))
+            .await?
+            .consensus_params;
+        let max_gas = u64::try_from(params.block.max_gas)
+            .map_err(|_| Error::Custom("Block gas is unlimited".to_string()))?;
+
+        let first = latest.value().saturating_sub(blocks.max(1) - 1).max(1);
+        let mut gas_used = 0u64;
+        let mut sampled = 0u64;
+        for height in first..=latest.value() {
+            let height = Height::try_from(height)?;
+            let results = self.request(|| self.client.block_results(height)).await?;
+            gas_used += results
+                .txs_results
+                .unwrap_or_default()
+                .iter()
+                .map(|result| u64::try_from(result.gas_used).unwrap_or_default())
+                .sum::<u64>();
+            sampled += 1;
+        }
+
+        if max_gas == 0 || sampled == 0 {
+            return Ok(0.0);
+        }
+
+        Ok(gas_used as f64 / (max_gas * sampled) as f64)
+    }
+}
+
+/// An open WebSocket subscription along with the task driving its socket.
+struct WsSubscription {
+    client: WebSocketClient,
+    driver: JoinHandle<()>,
+    subscription: Subscription,
+}
+
+impl WsSubscription {
+    /// Opens a WebSocket connection and subscribes to `query`.
+    async fn connect(url: &str, query: Query) -> CosmosResult<Self> {
+        let (client, driver) = WebSocketClient::new(url.parse::<WebSocketClientUrl>()?).await?;
+        let driver = tokio::spawn(async move {
+            let _ = driver.run().await;
+        });
+        let subscription = match client.subscribe(query).await {
+            Ok(subscription) => subscription,
+            Err(e) => {
+                let _ = client.close();
+                driver.abort();
+                return Err(e.into());
+            }
+        };
+
+        Ok(Self {
+            client,
+            driver,
+            subscription,
+        })
+    }
+
+    /// Closes the connection.
+    fn close(self) {
+        let _ = self.client.close();
+        self.driver.abort();
+    }
+}
+
+/// Derives the WebSocket URL of a Tendermint RPC endpoint.
+fn websocket_url(endpoint: &str) -> String {
+    let endpoint = endpoint.trim_end_matches('/');
+    let endpoint = match endpoint.split_once("://") {
+        Some(("https", rest)) => format!("wss://{rest}"),
+        Some(("http", rest)) => format!("ws://{rest}"),
+        _ => endpoint.to_string(),
+    };
+
+    if endpoint.ends_with("/websocket") {
+        endpoint
+    } else {
+        format!("{endpoint}/websocket")
     }
 }
 
@@ -32,19 +300,18 @@ impl Rpc for JsonRpc {
         M: Message + Default + 'static,
         R: Message + Default + 'static,
     {
-        let data = msg.encode_to_vec();
-        let res = self
-            .client
-            .abci_query(Some(path.to_string()), data, None, false)
-            .await?;
-
-        if res.code != Code::Ok {
-            return Err(Error::RpcError(res.log));
-        }
-
-        let proto_res = R::decode(res.value.as_slice())?;
+        self.abci_query(path, msg, None).await
+    }
 
-        Ok(proto_res)
+    /// Asynchronously queries the blockchain state at a past block height.
+    /// Returns the result as a CosmosResult.
+    async fn query_at_height<M, R>(&self, path: &str, msg: M, height: u64) -> CosmosResult<R>
+    where
+        Self: Sized,
+        M: Message + Default + 'static,
+        R: Message + Default + 'static,
+    {
+        self.abci_query(path, msg, Some(height)).await
     }
 
     /// Asynchronously simulates a transaction using the provided payload.
@@ -63,14 +330,61 @@ impl Rpc for JsonRpc {
     /// Asynchronously broadcasts a transaction without waiting for it to be included in a block.
     /// Returns the async response as a CosmosResult.
     async fn broadcast_tx_async(&self, payload: Vec<u8>) -> CosmosResult<TxAsyncResponse> {
-        let res = self.client.broadcast_tx_async(payload).await?;
-        Ok(res)
+        let payload = &payload;
+        let (res, retried) = with_retry_reporting(self.retry.as_ref(), move || async move {
+            with_timeout(self.timeout, async {
+                Ok(self.client.broadcast_tx_async(payload.clone()).await?)
+            })
+            .await
+        })
+        .await;
+
+        match res {
+            Err(e) if retried && is_duplicate_tx_error(&e) => Ok(TxAsyncResponse {
+                codespace: String::new(),
+                code: Code::Ok,
+                data: Bytes::new(),
+                log: String::new(),
+                hash: tx_hash(payload),
+            }),
+            Ok(mut resp) if retried && is_duplicate_tx(&resp.codespace, resp.code.value()) => {
+                resp.code = Code::Ok;
+                resp.hash = tx_hash(payload);
+                Ok(resp)
+            }
+            res => res,
+        }
     }
 
     /// Asynchronously broadcasts a transaction and waits for it to be included in a block.
     /// Returns the sync response as a CosmosResult.
+    ///
+    /// If a retried broadcast finds the transaction already in the node's mempool cache, an
+    /// earlier attempt was accepted, and the broadcast succeeds with the transaction's hash.
     async fn broadcast_tx_sync(&self, payload: Vec<u8>) -> CosmosResult<TxSyncResponse> {
-        let res = self.client.broadcast_tx_sync(payload).await?;
-        Ok(res)
+        let payload = &payload;
+        let (res, retried) = with_retry_reporting(self.retry.as_ref(), move || async move {
+            with_timeout(self.timeout, async {
+                Ok(self.client.broadcast_tx_sync(payload.clone()).await?)
+            })
+            .await
+        })
+        .await;
+
+        match res {
+            Err(e) if retried && is_duplicate_tx_error(&e) => Ok(TxSyncResponse {
+                codespace: String::new(),
+                code: Code::Ok,
+                data: Bytes::new(),
+                log: String::new(),
+                hash: tx_hash(payload),
+            }),
+            Ok(mut resp) if retried && is_duplicate_tx(&resp.codespace, resp.code.value()) => {
+                resp.code = Code::Ok;
+                resp.hash = tx_hash(payload);
+                Ok(resp)
+            }
+            res => res,
+        }
     }
 }
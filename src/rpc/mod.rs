@@ -6,7 +6,9 @@
 //!
 //! - `grpc`: Contains the gRPC client implementation.
 //! - `json_rpc`: Contains the JSON-RPC client implementation.
+//! - `retry`: Contains the retry and timeout policies shared by the RPC clients.
 //! - `types`: Contains types used across the RPC clients.
 pub mod grpc;
 pub mod json_rpc;
+pub mod retry;
 pub mod types;
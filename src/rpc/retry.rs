@@ -0,0 +1,115 @@
+use std::future::Future;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use cosmrs::rpc::error::ErrorDetail;
+
+use crate::error::{CosmosResult, Error};
+
+/// Policy for retrying RPC calls that fail with a transient network error, with exponential
+/// backoff between attempts.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RetryConfig {
+    /// Number of retries after the initial attempt.
+    pub max_retries: u32,
+    /// Delay before the first retry, doubled for each following retry.
+    pub base_delay: Duration,
+    /// Upper bound of the delay between two attempts.
+    pub max_delay: Duration,
+    /// Whether to randomize each delay between half and all of its value, so concurrent
+    /// clients do not retry in lockstep.
+    pub jitter: bool,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            base_delay: Duration::from_millis(200),
+            max_delay: Duration::from_secs(5),
+            jitter: true,
+        }
+    }
+}
+
+impl RetryConfig {
+    /// Computes the delay before the given retry, starting at 0.
+    fn delay(&self, retry: u32) -> Duration {
+        let delay = self
+            .base_delay
+            .saturating_mul(2u32.saturating_pow(retry))
+            .min(self.max_delay);
+        if !self.jitter {
+            return delay;
+        }
+
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .subsec_nanos();
+        delay / 2 + delay.mul_f64(f64::from(nanos % 1000) / 2000.0)
+    }
+}
+
+/// Whether an error is a connection or timeout failure worth retrying. Deterministic failures,
+/// such as a non-OK ABCI code or a decode error, would fail again and are never retried.
+fn is_transient(err: &Error) -> bool {
+    match err {
+        Error::TonicError(_) | Error::Timeout(_) => true,
+        Error::TonicStatus(status) => matches!(
+            status.code(),
+            tonic::Code::Unavailable | tonic::Code::DeadlineExceeded
+        ),
+        Error::TendermintRpcError(err) => {
+            matches!(err.detail(), ErrorDetail::Io(_) | ErrorDetail::Http(_))
+        }
+        _ => false,
+    }
+}
+
+/// Runs `call`, retrying it according to `config` while it fails with a transient error.
+pub(crate) async fn with_retry<F, Fut, R>(config: Option<&RetryConfig>, call: F) -> CosmosResult<R>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = CosmosResult<R>>,
+{
+    with_retry_reporting(config, call).await.0
+}
+
+/// Like `with_retry`, also reporting whether the result comes from a retry, in which case an
+/// earlier attempt may have reached the node even though it failed on the client's side.
+pub(crate) async fn with_retry_reporting<F, Fut, R>(
+    config: Option<&RetryConfig>,
+    mut call: F,
+) -> (CosmosResult<R>, bool)
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = CosmosResult<R>>,
+{
+    let Some(config) = config else {
+        return (call().await, false);
+    };
+
+    let mut retry = 0;
+    loop {
+        match call().await {
+            Err(err) if retry < config.max_retries && is_transient(&err) => {
+                tokio::time::sleep(config.delay(retry)).await;
+                retry += 1;
+            }
+            res => return (res, retry > 0),
+        }
+    }
+}
+
+/// Runs `fut`, failing with `Error::Timeout` if it does not complete within `timeout`.
+pub(crate) async fn with_timeout<Fut, R>(timeout: Option<Duration>, fut: Fut) -> CosmosResult<R>
+where
+    Fut: Future<Output = CosmosResult<R>>,
+{
+    match timeout {
+        Some(timeout) => tokio::time::timeout(timeout, fut)
+            .await
+            .map_err(|_| Error::Timeout(timeout))?,
+        None => fut.await,
+    }
+}
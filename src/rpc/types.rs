@@ -1,10 +1,32 @@
 use async_trait::async_trait;
+use cosmrs::tendermint::Hash;
 use cosmrs::{
     proto::{cosmos::tx::v1beta1::SimulateResponse, prost::Message},
     rpc::endpoint::broadcast::{tx_async, tx_sync},
 };
+use sha2::{Digest, Sha256};
 
-use crate::error::CosmosResult;
+use crate::error::{CosmosResult, Error};
+
+/// Error CometBFT returns when broadcasting a transaction already in its mempool cache.
+const TX_IN_CACHE: &str = "tx already exists in cache";
+
+/// SDK error code of `ErrTxInMempoolCache`, which the SDK's gRPC broadcast reports instead.
+const SDK_TX_IN_MEMPOOL_CACHE_CODE: u32 = 19;
+
+/// Codespaces and codes of the errors reporting a missing entry on queries served over ABCI:
+/// the SDK's `ErrNotFound`, to which gRPC `NotFound` statuses are mapped, wasmd's
+/// `ErrNoSuchContract` and the fee grant module's `ErrNoAllowance`.
+const NOT_FOUND_CODES: [(&str, u32); 3] = [("sdk", 38), ("wasm", 22), ("feegrant", 5)];
+
+/// Fragments of the errors nodes return when the state or block at a requested height has
+/// been pruned or does not exist yet.
+const HEIGHT_NOT_AVAILABLE: [&str; 4] = [
+    "failed to load state at height",
+    "version does not exist",
+    "is not available, lowest height is",
+    "must be less than or equal to the current blockchain height",
+];
 
 /// Type alias for the synchronous response of a broadcasted transaction.
 pub type TxSyncResponse = tx_sync::Response;
@@ -23,6 +45,14 @@ pub trait Rpc {
         M: Message + Default + 'static,
         R: Message + Default + 'static;
 
+    /// Asynchronously queries the blockchain state at a past block height.
+    /// Returns the result as a CosmosResult.
+    async fn query_at_height<M, R>(&self, path: &str, msg: M, height: u64) -> CosmosResult<R>
+    where
+        Self: Sized,
+        M: Message + Default + 'static,
+        R: Message + Default + 'static;
+
     /// Asynchronously simulates a transaction using the provided payload.
     /// Returns the simulation response as a CosmosResult.
     async fn simulate_tx(&self, payload: Vec<u8>) -> CosmosResult<SimulateResponse>;
@@ -35,3 +65,138 @@ pub trait Rpc {
     /// Returns the async response as a CosmosResult.
     async fn broadcast_tx_async(&self, payload: Vec<u8>) -> CosmosResult<TxAsyncResponse>;
 }
+
+/// Converts the error of a query at `height` into `Error::HeightNotAvailable` if the node
+/// reported that it cannot serve that height, leaving other errors unchanged.
+pub(crate) fn map_height_error(height: u64, error: Error) -> Error {
+    let message = match &error {
+        Error::TonicStatus(status) => status.message(),
+        Error::AbciQueryFailed { log, .. } => log.as_str(),
+        _ => return error,
+    };
+
+    if HEIGHT_NOT_AVAILABLE
+        .iter()
+        .any(|fragment| message.contains(fragment))
+    {
+        return Error::HeightNotAvailable {
+            requested: height,
+            earliest: lowest_height(message),
+            message: message.to_string(),
+        };
+    }
+
+    error
+}
+
+/// Parses the lowest height a node retains from its "height X is not available, lowest height is
+/// Y" error, if the message reports it.
+fn lowest_height(message: &str) -> Option<u64> {
+    let (_, rest) = message.split_once("lowest height is ")?;
+    let digits = rest
+        .find(|c: char| !c.is_ascii_digit())
+        .map_or(rest, |end| &rest[..end]);
+    digits.parse().ok()
+}
+
+/// Returns whether a broadcast result reports that the transaction is already in the node's
+/// mempool cache.
+///
+/// On a retried broadcast, this means an earlier attempt was accepted even though it failed on
+/// the client's side, e.g. with a timeout, so the broadcast succeeded.
+pub(crate) fn is_duplicate_tx(codespace: &str, code: u32) -> bool {
+    codespace == "sdk" && code == SDK_TX_IN_MEMPOOL_CACHE_CODE
+}
+
+/// Like `is_duplicate_tx`, for the RPC error CometBFT returns instead of a response.
+pub(crate) fn is_duplicate_tx_error(error: &Error) -> bool {
+    match error {
+        Error::TendermintRpcError(e) => e.to_string().contains(TX_IN_CACHE),
+        Error::RpcError(log) => log.contains(TX_IN_CACHE),
+        _ => false,
+    }
+}
+
+/// Returns whether a query failed because the queried entry does not exist, as reported by a
+/// gRPC `NotFound` status or by the codespace and code of an ABCI query.
+pub(crate) fn is_not_found_error(error: &Error) -> bool {
+    match error {
+        Error::TonicStatus(status) => status.code() == tonic::Code::NotFound,
+        Error::AbciQueryFailed {
+            codespace, code, ..
+        } => NOT_FOUND_CODES.contains(&(codespace.as_str(), *code)),
+        _ => false,
+    }
+}
+
+/// Computes the hash of an encoded transaction, as used by the chain to identify it.
+pub(crate) fn tx_hash(payload: &[u8]) -> Hash {
+    Hash::Sha256(Sha256::digest(payload).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn query_error(log: &str) -> Error {
+        Error::AbciQueryFailed {
+            codespace: "sdk".to_string(),
+            code: 18,
+            log: log.to_string(),
+        }
+    }
+
+    #[test]
+    fn map_height_error_reports_lowest_height() {
+        let error = query_error("height 5 is not available, lowest height is 120");
+
+        match map_height_error(5, error) {
+            Error::HeightNotAvailable {
+                requested,
+                earliest,
+                ..
+            } => {
+                assert_eq!(requested, 5);
+                assert_eq!(earliest, Some(120));
+            }
+            other => panic!("unexpected error: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn map_height_error_without_lowest_height() {
+        let error = query_error("failed to load state at height 5; version does not exist");
+
+        assert!(matches!(
+            map_height_error(5, error),
+            Error::HeightNotAvailable { earliest: None, .. }
+        ));
+        assert!(matches!(
+            map_height_error(5, query_error("out of gas")),
+            Error::AbciQueryFailed { .. }
+        ));
+    }
+
+    #[test]
+    fn not_found_errors() {
+        let not_found = |codespace: &str, code| Error::AbciQueryFailed {
+            codespace: codespace.to_string(),
+            code,
+            log: "not found".to_string(),
+        };
+
+        assert!(is_not_found_error(&Error::TonicStatus(
+            tonic::Status::not_found("tx")
+        )));
+        assert!(is_not_found_error(&not_found("sdk", 38)));
+        assert!(is_not_found_error(&not_found("wasm", 22)));
+        assert!(is_not_found_error(&not_found("feegrant", 5)));
+        assert!(!is_not_found_error(&not_found("wasm", 38)));
+        assert!(!is_not_found_error(&Error::TonicStatus(
+            tonic::Status::unavailable("down")
+        )));
+        assert!(!is_not_found_error(&Error::RpcError(
+            "not found".to_string()
+        )));
+    }
+}
@@ -0,0 +1,200 @@
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use cosmrs::crypto::PublicKey;
+use cosmrs::AccountId;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::error::{CosmosResult, Error};
+use crate::signer::{verify_digest, KeyType, Signer};
+
+/// Amino JSON public key, as found in the `pub_key` of a legacy `StdSignature`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AminoPubKey {
+    /// Amino type of the key, e.g. `tendermint/PubKeySecp256k1`.
+    #[serde(rename = "type")]
+    pub kind: String,
+    /// Base64 encoded compressed public key.
+    pub value: String,
+}
+
+/// Signature over ADR-036 arbitrary data, in the `StdSignature` shape returned by Keplr's
+/// `signArbitrary`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SignatureResponse {
+    /// The public key of the signer.
+    pub pub_key: AminoPubKey,
+    /// Base64 encoded 64-byte compact signature.
+    pub signature: String,
+}
+
+impl Signer {
+    /// Signs arbitrary data following ADR-036, like Keplr's `signArbitrary`.
+    ///
+    /// The sign doc wraps `data` in a single `sign/MsgSignData` message from the signer, with
+    /// an empty chain id, zero account number and sequence, and no fee or memo.
+    ///
+    /// # Returns
+    ///
+    /// The base64 encoded signature and amino public key, verifiable with `verify_arbitrary`.
+    pub async fn sign_arbitrary(&self, data: &[u8]) -> CosmosResult<SignatureResponse> {
+        let sign_doc = sign_doc(self.public_address.as_ref(), data);
+        let signature = self.backend.sign(&sign_doc).await?;
+
+        Ok(SignatureResponse {
+            pub_key: AminoPubKey {
+                kind: amino_key_type(self.key_type).to_string(),
+                value: STANDARD.encode(self.public_key.to_bytes()),
+            },
+            signature: STANDARD.encode(signature),
+        })
+    }
+}
+
+/// Verifies an ADR-036 signature over `data` by `signer`, such as one produced by Keplr's
+/// `signArbitrary` or `Signer::sign_arbitrary`.
+///
+/// # Returns
+///
+/// `true` if the public key belongs to `signer` and the signature is valid, `false` otherwise.
+///
+/// # Errors
+///
+/// * Returns an error if `signer` is not a bech32 address, or the public key or signature is
+///   malformed or of an unsupported type.
+pub fn verify_arbitrary(
+    signer: &str,
+    data: &[u8],
+    response: &SignatureResponse,
+) -> CosmosResult<bool> {
+    let key_type = match response.pub_key.kind.as_str() {
+        "tendermint/PubKeySecp256k1" => KeyType::Secp256k1,
+        "ethermint/PubKeyEthSecp256k1" => KeyType::EthSecp256k1,
+        "injective/PubKeyEthSecp256k1" => KeyType::InjectiveEthSecp256k1,
+        kind => {
+            return Err(Error::Custom(format!(
+                "Unsupported ADR-036 public key type {kind}"
+            )))
+        }
+    };
+
+    let key_bytes = STANDARD
+        .decode(&response.pub_key.value)
+        .map_err(|e| Error::wrap("Invalid ADR-036 public key encoding", e))?;
+    let public_key = PublicKey::from_raw_secp256k1(&key_bytes)
+        .ok_or_else(|| Error::Custom("Invalid ADR-036 public key".to_string()))?;
+    let signature = STANDARD
+        .decode(&response.signature)
+        .map_err(|e| Error::wrap("Invalid ADR-036 signature encoding", e))?;
+
+    let address: AccountId = signer.parse()?;
+    if key_type.account_id(&public_key, address.prefix())? != address {
+        return Ok(false);
+    }
+
+    verify_digest(
+        &public_key,
+        &key_type.digest(&sign_doc(signer, data)),
+        &signature,
+    )
+}
+
+/// Builds the canonical (sorted, compact) amino JSON ADR-036 sign doc for `data`.
+fn sign_doc(signer: &str, data: &[u8]) -> Vec<u8> {
+    json!({
+        "account_number": "0",
+        "chain_id": "",
+        "fee": { "amount": [], "gas": "0" },
+        "memo": "",
+        "msgs": [{
+            "type": "sign/MsgSignData",
+            "value": {
+                "data": STANDARD.encode(data),
+                "signer": signer,
+            },
+        }],
+        "sequence": "0",
+    })
+    .to_string()
+    .into_bytes()
+}
+
+/// Returns the amino type of public keys of the given type.
+fn amino_key_type(key_type: KeyType) -> &'static str {
+    match key_type {
+        KeyType::Secp256k1 => "tendermint/PubKeySecp256k1",
+        KeyType::EthSecp256k1 => "ethermint/PubKeyEthSecp256k1",
+        KeyType::InjectiveEthSecp256k1 => "injective/PubKeyEthSecp256k1",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use rust_decimal::Decimal;
+
+    use super::*;
+
+    const PRIVATE_KEY: &str = "0101010101010101010101010101010101010101010101010101010101010101";
+    const ADDRESS: &str = "cosmos10xcqpzrky6eff2g52qdye53xkk9jxkvrpq6uqr";
+    const DATA: &[u8] = b"Sign in to cosmos-client";
+
+    /// Response in the shape of Keplr's `signArbitrary` for the key above over `DATA`, signed
+    /// with OpenSSL rather than this crate.
+    fn known_response() -> SignatureResponse {
+        SignatureResponse {
+            pub_key: AminoPubKey {
+                kind: "tendermint/PubKeySecp256k1".to_string(),
+                value: "AxuExVZ7EmRAmV0+1aq6BWXXHhg0YEgZ/5wX9enV3QeP".to_string(),
+            },
+            signature: "rsG+bQZBwpF6Rg/f43Rs42rqhrIGtKkS+wWHYRZ27O5lXILYbv0/Nsyh3DMTtlULuRdgHhQJHgBAw5RFg4D+3w=="
+                .to_string(),
+        }
+    }
+
+    fn signer(private_key: &str) -> Signer {
+        Signer::from_private_key(
+            private_key,
+            "cosmos",
+            "uatom",
+            1.3,
+            Decimal::from_str("0.025").unwrap(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn sign_doc_is_canonical_amino_json() {
+        assert_eq!(
+            String::from_utf8(sign_doc(ADDRESS, DATA)).unwrap(),
+            r#"{"account_number":"0","chain_id":"","fee":{"amount":[],"gas":"0"},"memo":"","msgs":[{"type":"sign/MsgSignData","value":{"data":"U2lnbiBpbiB0byBjb3Ntb3MtY2xpZW50","signer":"cosmos10xcqpzrky6eff2g52qdye53xkk9jxkvrpq6uqr"}}],"sequence":"0"}"#
+        );
+    }
+
+    #[test]
+    fn verifies_a_known_signature() {
+        assert!(verify_arbitrary(ADDRESS, DATA, &known_response()).unwrap());
+        assert!(!verify_arbitrary(ADDRESS, b"other data", &known_response()).unwrap());
+    }
+
+    #[tokio::test]
+    async fn sign_arbitrary_round_trips() {
+        let signer = signer(PRIVATE_KEY);
+        assert_eq!(signer.public_address.to_string(), ADDRESS);
+
+        let response = signer.sign_arbitrary(DATA).await.unwrap();
+        assert_eq!(response.pub_key, known_response().pub_key);
+        assert!(verify_arbitrary(ADDRESS, DATA, &response).unwrap());
+    }
+
+    #[tokio::test]
+    async fn rejects_a_signature_for_another_signer() {
+        let other = signer("0202020202020202020202020202020202020202020202020202020202020202");
+        let response = other.sign_arbitrary(DATA).await.unwrap();
+
+        assert!(!verify_arbitrary(ADDRESS, DATA, &response).unwrap());
+        let other_address = other.public_address.to_string();
+        assert!(!verify_arbitrary(&other_address, DATA, &known_response()).unwrap());
+    }
+}
@@ -0,0 +1,234 @@
+use cosmrs::proto::cosmos::bank::v1beta1::MsgSend;
+use cosmrs::proto::cosmos::base::v1beta1::Coin as ProtoCoin;
+use cosmrs::proto::cosmos::distribution::v1beta1::MsgWithdrawDelegatorReward;
+use cosmrs::proto::cosmos::gov::v1beta1::MsgVote;
+use cosmrs::proto::cosmos::staking::v1beta1::{MsgBeginRedelegate, MsgDelegate, MsgUndelegate};
+use cosmrs::proto::prost::Message;
+use cosmrs::tx::{AccountNumber, Body, Fee, SequenceNumber};
+use cosmrs::Any;
+use serde_json::{json, Value};
+
+use crate::error::{CosmosResult, Error};
+
+/// Builds the canonical (sorted, compact) legacy amino JSON `StdSignDoc` of a transaction.
+///
+/// Only messages with a known amino representation can be signed this way; others fail with
+/// `Error::UnknownCosmosMsg`.
+pub(crate) fn std_sign_doc(
+    chain_id: &str,
+    account_number: AccountNumber,
+    sequence: SequenceNumber,
+    fee: &Fee,
+    body: &Body,
+) -> CosmosResult<Vec<u8>> {
+    let msgs = body
+        .messages
+        .iter()
+        .map(amino_msg)
+        .collect::<CosmosResult<Vec<_>>>()?;
+
+    let mut fee_json = json!({
+        "amount": fee
+            .amount
+            .iter()
+            .map(|coin| json!({ "amount": coin.amount.to_string(), "denom": coin.denom.to_string() }))
+            .collect::<Vec<_>>(),
+        "gas": fee.gas_limit.to_string(),
+    });
+    if let Some(payer) = &fee.payer {
+        fee_json["payer"] = json!(payer.to_string());
+    }
+    if let Some(granter) = &fee.granter {
+        fee_json["granter"] = json!(granter.to_string());
+    }
+
+    let mut sign_doc = json!({
+        "account_number": account_number.to_string(),
+        "chain_id": chain_id,
+        "fee": fee_json,
+        "memo": body.memo,
+        "msgs": msgs,
+        "sequence": sequence.to_string(),
+    });
+    if body.timeout_height.value() != 0 {
+        sign_doc["timeout_height"] = json!(body.timeout_height.value().to_string());
+    }
+
+    Ok(escape_html(&sign_doc.to_string()).into_bytes())
+}
+
+/// Escapes `<`, `>` and `&` as `\u003c`, `\u003e` and `\u0026`, and the U+2028 and U+2029
+/// line separators, as Go's `json.Marshal` does in the SDK's `MustSortJSON`. The signature
+/// only verifies if the sign bytes match the SDK's byte for byte.
+///
+/// These characters cannot appear in JSON outside of strings, so the output stays valid.
+fn escape_html(json: &str) -> String {
+    let mut escaped = String::with_capacity(json.len());
+    for c in json.chars() {
+        match c {
+            '<' => escaped.push_str("\\u003c"),
+            '>' => escaped.push_str("\\u003e"),
+            '&' => escaped.push_str("\\u0026"),
+            '\u{2028}' => escaped.push_str("\\u2028"),
+            '\u{2029}' => escaped.push_str("\\u2029"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Converts a message to its amino JSON `{type, value}` representation.
+fn amino_msg(msg: &Any) -> CosmosResult<Value> {
+    let value = msg.value.as_slice();
+    let (amino_type, value) = match msg.type_url.as_str() {
+        "/cosmos.bank.v1beta1.MsgSend" => {
+            let msg = MsgSend::decode(value)?;
+            (
+                "cosmos-sdk/MsgSend",
+                json!({
+                    "amount": coins(&msg.amount),
+                    "from_address": msg.from_address,
+                    "to_address": msg.to_address,
+                }),
+            )
+        }
+        "/cosmos.staking.v1beta1.MsgDelegate" => {
+            let msg = MsgDelegate::decode(value)?;
+            (
+                "cosmos-sdk/MsgDelegate",
+                json!({
+                    "amount": msg.amount.as_ref().map(coin),
+                    "delegator_address": msg.delegator_address,
+                    "validator_address": msg.validator_address,
+                }),
+            )
+        }
+        "/cosmos.staking.v1beta1.MsgUndelegate" => {
+            let msg = MsgUndelegate::decode(value)?;
+            (
+                "cosmos-sdk/MsgUndelegate",
+                json!({
+                    "amount": msg.amount.as_ref().map(coin),
+                    "delegator_address": msg.delegator_address,
+                    "validator_address": msg.validator_address,
+                }),
+            )
+        }
+        "/cosmos.staking.v1beta1.MsgBeginRedelegate" => {
+            let msg = MsgBeginRedelegate::decode(value)?;
+            (
+                "cosmos-sdk/MsgBeginRedelegate",
+                json!({
+                    "amount": msg.amount.as_ref().map(coin),
+                    "delegator_address": msg.delegator_address,
+                    "validator_dst_address": msg.validator_dst_address,
+                    "validator_src_address": msg.validator_src_address,
+                }),
+            )
+        }
+        "/cosmos.distribution.v1beta1.MsgWithdrawDelegatorReward" => {
+            let msg = MsgWithdrawDelegatorReward::decode(value)?;
+            (
+                "cosmos-sdk/MsgWithdrawDelegationReward",
+                json!({
+                    "delegator_address": msg.delegator_address,
+                    "validator_address": msg.validator_address,
+                }),
+            )
+        }
+        "/cosmos.gov.v1beta1.MsgVote" => {
+            let msg = MsgVote::decode(value)?;
+            (
+                "cosmos-sdk/MsgVote",
+                json!({
+                    "option": msg.option,
+                    "proposal_id": msg.proposal_id.to_string(),
+                    "voter": msg.voter,
+                }),
+            )
+        }
+        _ => return Err(Error::UnknownCosmosMsg),
+    };
+
+    Ok(json!({ "type": amino_type, "value": value }))
+}
+
+fn coin(coin: &ProtoCoin) -> Value {
+    json!({ "amount": coin.amount, "denom": coin.denom })
+}
+
+fn coins(coins: &[ProtoCoin]) -> Value {
+    Value::Array(coins.iter().map(coin).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmrs::tx::{Body, Fee};
+    use cosmrs::{Any, Coin};
+
+    use super::*;
+
+    const FROM: &str = "cosmos1pkptre7fdkl6gfrzlesjjvhxhlc3r4gmmk8rs6";
+    const TO: &str = "cosmos10dyr9899g6t0pelew4nvf4j5c3jcgv0r73qga5";
+
+    fn send_body(memo: &str) -> Body {
+        let msg = Any::from_msg(&MsgSend {
+            from_address: FROM.to_string(),
+            to_address: TO.to_string(),
+            amount: vec![ProtoCoin {
+                denom: "uatom".to_string(),
+                amount: "1000".to_string(),
+            }],
+        })
+        .unwrap();
+        Body::new(vec![msg], memo, 0u32)
+    }
+
+    fn fee() -> Fee {
+        Fee::from_amount_and_gas(
+            Coin {
+                denom: "uatom".parse().unwrap(),
+                amount: 2000,
+            },
+            80_000u64,
+        )
+    }
+
+    /// Sign bytes of the SDK's `legacytx.StdSignBytes` for the same transaction.
+    fn expected(memo: &str) -> String {
+        format!(
+            concat!(
+                r#"{{"account_number":"7","chain_id":"cosmoshub-4","#,
+                r#""fee":{{"amount":[{{"amount":"2000","denom":"uatom"}}],"gas":"80000"}},"#,
+                r#""memo":"{}","msgs":[{{"type":"cosmos-sdk/MsgSend","value":{{"#,
+                r#""amount":[{{"amount":"1000","denom":"uatom"}}],"#,
+                r#""from_address":"{}","to_address":"{}"}}}}],"sequence":"3"}}"#,
+            ),
+            memo, FROM, TO
+        )
+    }
+
+    #[test]
+    fn msg_send_matches_sdk_sign_bytes() {
+        let sign_doc = std_sign_doc("cosmoshub-4", 7, 3, &fee(), &send_body("hello")).unwrap();
+        assert_eq!(String::from_utf8(sign_doc).unwrap(), expected("hello"));
+    }
+
+    #[test]
+    fn html_characters_are_escaped_like_go() {
+        let sign_doc = std_sign_doc("cosmoshub-4", 7, 3, &fee(), &send_body("a & b <c>")).unwrap();
+        assert_eq!(
+            String::from_utf8(sign_doc).unwrap(),
+            expected(r"a \u0026 b \u003cc\u003e")
+        );
+    }
+
+    #[test]
+    fn timeout_height_is_included_when_set() {
+        let mut body = send_body("");
+        body.timeout_height = 100u32.into();
+        let sign_doc =
+            String::from_utf8(std_sign_doc("cosmoshub-4", 7, 3, &fee(), &body).unwrap()).unwrap();
+        assert!(sign_doc.ends_with(r#""sequence":"3","timeout_height":"100"}"#));
+    }
+}
@@ -0,0 +1,105 @@
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use cosmrs::crypto::secp256k1::SigningKey;
+use cosmrs::crypto::PublicKey;
+use k256::ecdsa::signature::hazmat::PrehashSigner;
+use k256::ecdsa::Signature;
+use sha3::{Digest, Keccak256};
+
+use crate::error::{CosmosResult, Error};
+
+/// Source of secp256k1 signatures for a `Signer`, e.g. an in-memory key or a hardware wallet.
+#[async_trait]
+pub trait SigningBackend: Debug + Send + Sync {
+    /// Returns the public key of the signing key.
+    fn public_key(&self) -> PublicKey;
+
+    /// Whether the backend can sign SIGN_MODE_DIRECT sign docs. Backends that only sign amino
+    /// JSON, such as the Ledger Cosmos app, return `false`.
+    fn supports_direct(&self) -> bool {
+        true
+    }
+
+    /// Signs the given sign bytes, hashed with sha256, and returns the 64-byte compact
+    /// signature.
+    async fn sign(&self, sign_bytes: &[u8]) -> CosmosResult<Vec<u8>>;
+}
+
+/// Backend signing with a private key held in process memory.
+#[derive(Clone)]
+pub struct SoftwareBackend {
+    key: Arc<SigningKey>,
+}
+
+impl SoftwareBackend {
+    /// Creates a new `SoftwareBackend` around the given key.
+    pub fn new(key: Arc<SigningKey>) -> Self {
+        Self { key }
+    }
+}
+
+impl Debug for SoftwareBackend {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SoftwareBackend")
+            .field("public_key", &self.key.public_key())
+            .finish_non_exhaustive()
+    }
+}
+
+#[async_trait]
+impl SigningBackend for SoftwareBackend {
+    fn public_key(&self) -> PublicKey {
+        self.key.public_key()
+    }
+
+    async fn sign(&self, sign_bytes: &[u8]) -> CosmosResult<Vec<u8>> {
+        Ok(self.key.sign(sign_bytes)?.to_bytes().to_vec())
+    }
+}
+
+/// Backend signing with an `eth_secp256k1` private key held in process memory, which signs the
+/// Keccak256 digest of the sign bytes instead of their SHA256 digest.
+#[derive(Clone)]
+pub struct EthSoftwareBackend {
+    key: k256::ecdsa::SigningKey,
+    public_key: PublicKey,
+}
+
+impl EthSoftwareBackend {
+    /// Creates a backend signing with the given key.
+    pub fn new(key: k256::ecdsa::SigningKey) -> CosmosResult<Self> {
+        let point = key.verifying_key().to_encoded_point(true);
+        let public_key = PublicKey::from_raw_secp256k1(point.as_bytes())
+            .ok_or_else(|| Error::Custom("Invalid eth_secp256k1 public key".to_string()))?;
+
+        Ok(Self { key, public_key })
+    }
+}
+
+impl Debug for EthSoftwareBackend {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EthSoftwareBackend")
+            .field("public_key", &self.public_key)
+            .finish_non_exhaustive()
+    }
+}
+
+#[async_trait]
+impl SigningBackend for EthSoftwareBackend {
+    fn public_key(&self) -> PublicKey {
+        self.public_key
+    }
+
+    async fn sign(&self, sign_bytes: &[u8]) -> CosmosResult<Vec<u8>> {
+        let digest = Keccak256::digest(sign_bytes);
+        let signature: Signature = self
+            .key
+            .sign_prehash(&digest)
+            .map_err(|e| Error::wrap("Cannot sign with eth_secp256k1 key", e))?;
+        let signature = signature.normalize_s().unwrap_or(signature);
+
+        Ok(signature.to_bytes().to_vec())
+    }
+}
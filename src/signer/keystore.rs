@@ -0,0 +1,250 @@
+use aes_gcm::aead::{Aead, KeyInit};
+use aes_gcm::{Aes256Gcm, Key, Nonce};
+use cosmrs::bip32::secp256k1::elliptic_curve::rand_core::{OsRng, RngCore};
+use serde::{Deserialize, Serialize};
+
+use crate::error::{CosmosResult, Error};
+use crate::signer::{KeyType, Signer};
+
+/// Version of the keystore format written by `Signer::encrypt_to_json`.
+const KEYSTORE_VERSION: u32 = 1;
+/// Cipher encrypting the mnemonic.
+const CIPHER: &str = "aes-256-gcm";
+/// Key derivation function turning the password into the cipher key.
+const KDF: &str = "scrypt";
+/// scrypt cost parameters, as recommended for interactive logins.
+const SCRYPT_LOG_N: u8 = 15;
+const SCRYPT_R: u32 = 8;
+const SCRYPT_P: u32 = 1;
+/// Length of the derived AES-256 key.
+const KEY_LEN: usize = 32;
+const SALT_LEN: usize = 32;
+/// Length of the AES-GCM nonce.
+const NONCE_LEN: usize = 12;
+
+/// Password-encrypted signer, serialized as JSON.
+#[derive(Debug, Serialize, Deserialize)]
+struct Keystore {
+    version: u32,
+    address: String,
+    denom: String,
+    derivation: String,
+    #[serde(default)]
+    key_type: KeyType,
+    gas_adjustment: f64,
+    gas_price: String,
+    crypto: KeystoreCrypto,
+}
+
+/// Encrypted mnemonic and the parameters needed to decrypt it.
+#[derive(Debug, Serialize, Deserialize)]
+struct KeystoreCrypto {
+    cipher: String,
+    /// Hex encoded ciphertext, including the GCM authentication tag.
+    ciphertext: String,
+    /// Hex encoded nonce.
+    nonce: String,
+    kdf: String,
+    kdfparams: ScryptParams,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ScryptParams {
+    log_n: u8,
+    r: u32,
+    p: u32,
+    dklen: usize,
+    /// Hex encoded salt.
+    salt: String,
+}
+
+impl Signer {
+    /// Encrypts the signer's mnemonic with `password` into a JSON keystore, using scrypt to
+    /// derive an AES-256-GCM key.
+    ///
+    /// The keystore also records the address, derivation path, denom and gas settings, so
+    /// `decrypt_from_json` restores an equivalent signer.
+    ///
+    /// # Errors
+    ///
+    /// * Returns `Error::Keystore` if the signer was not created from a mnemonic.
+    pub fn encrypt_to_json(&self, password: &str) -> CosmosResult<String> {
+        self.encrypt_with_log_n(password, SCRYPT_LOG_N)
+    }
+
+    /// Encrypts the signer's mnemonic like `encrypt_to_json`, with scrypt cost `log_n`.
+    fn encrypt_with_log_n(&self, password: &str, log_n: u8) -> CosmosResult<String> {
+        let (mnemonic, derivation) = match (&self.mnemonic, &self.derivation) {
+            (Some(mnemonic), Some(derivation)) => (mnemonic, derivation),
+            _ => {
+                return Err(Error::Keystore(
+                    "Only signers created from a mnemonic can be encrypted".to_string(),
+                ))
+            }
+        };
+
+        let mut salt = [0u8; SALT_LEN];
+        OsRng.fill_bytes(&mut salt);
+        let mut nonce = [0u8; NONCE_LEN];
+        OsRng.fill_bytes(&mut nonce);
+
+        let kdfparams = ScryptParams {
+            log_n,
+            r: SCRYPT_R,
+            p: SCRYPT_P,
+            dklen: KEY_LEN,
+            salt: hex::encode(salt),
+        };
+        let key = derive_key(password, &kdfparams)?;
+        let ciphertext = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&key))
+            .encrypt(Nonce::from_slice(&nonce), mnemonic.as_bytes())
+            .map_err(|_| Error::Keystore("Cannot encrypt the mnemonic".to_string()))?;
+
+        let keystore = Keystore {
+            version: KEYSTORE_VERSION,
+            address: self.public_address.to_string(),
+            denom: self.denom.clone(),
+            derivation: derivation.clone(),
+            key_type: self.key_type,
+            gas_adjustment: self.gas_adjustment,
+            gas_price: self.gas_price.to_string(),
+            crypto: KeystoreCrypto {
+                cipher: CIPHER.to_string(),
+                ciphertext: hex::encode(ciphertext),
+                nonce: hex::encode(nonce),
+                kdf: KDF.to_string(),
+                kdfparams,
+            },
+        };
+
+        Ok(serde_json::to_string(&keystore)?)
+    }
+
+    /// Decrypts a JSON keystore written by `encrypt_to_json` with `password`.
+    ///
+    /// # Errors
+    ///
+    /// * Returns `Error::Keystore` if the keystore format is unsupported, the password is
+    ///   wrong, or the decrypted key does not match the recorded address.
+    pub fn decrypt_from_json(json: &str, password: &str) -> CosmosResult<Self> {
+        let keystore: Keystore = serde_json::from_str(json)?;
+        if keystore.version != KEYSTORE_VERSION
+            || keystore.crypto.cipher != CIPHER
+            || keystore.crypto.kdf != KDF
+        {
+            return Err(Error::Keystore("Unsupported keystore format".to_string()));
+        }
+
+        let nonce = hex::decode(&keystore.crypto.nonce)?;
+        if nonce.len() != NONCE_LEN || keystore.crypto.kdfparams.dklen != KEY_LEN {
+            return Err(Error::Keystore("Unsupported keystore format".to_string()));
+        }
+
+        let key = derive_key(password, &keystore.crypto.kdfparams)?;
+        let mnemonic = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&key))
+            .decrypt(
+                Nonce::from_slice(&nonce),
+                hex::decode(&keystore.crypto.ciphertext)?.as_slice(),
+            )
+            .map_err(|_| Error::Keystore("Invalid password or corrupted keystore".to_string()))?;
+        let mnemonic = String::from_utf8(mnemonic)
+            .map_err(|_| Error::Keystore("Invalid password or corrupted keystore".to_string()))?;
+
+        let address: cosmrs::AccountId = keystore.address.parse()?;
+        let gas_price = keystore
+            .gas_price
+            .parse()
+            .map_err(|_| Error::Keystore("Invalid gas price".to_string()))?;
+        let signer = Signer::from_mnemonic_with_key_type(
+            &mnemonic,
+            address.prefix(),
+            &keystore.denom,
+            Some(&keystore.derivation),
+            keystore.key_type,
+            keystore.gas_adjustment,
+            gas_price,
+        )?;
+        if signer.public_address != address {
+            return Err(Error::Keystore(
+                "Decrypted key does not match the keystore address".to_string(),
+            ));
+        }
+
+        Ok(signer)
+    }
+}
+
+/// Derives the cipher key from `password` with scrypt.
+fn derive_key(password: &str, params: &ScryptParams) -> CosmosResult<[u8; KEY_LEN]> {
+    let salt = hex::decode(&params.salt)?;
+    let scrypt_params = scrypt::Params::new(params.log_n, params.r, params.p, KEY_LEN)
+        .map_err(|e| Error::Keystore(format!("Invalid scrypt parameters: {e}")))?;
+
+    let mut key = [0u8; KEY_LEN];
+    scrypt::scrypt(password.as_bytes(), &salt, &scrypt_params, &mut key)
+        .map_err(|e| Error::Keystore(format!("Cannot derive the keystore key: {e}")))?;
+
+    Ok(key)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use rust_decimal::Decimal;
+
+    use super::*;
+
+    const MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon abandon abandon \
+                            abandon abandon abandon about";
+    /// Cheap scrypt cost, so the tests do not spend seconds deriving keys.
+    const TEST_LOG_N: u8 = 4;
+
+    fn signer() -> Signer {
+        Signer::from_mnemonic(
+            MNEMONIC,
+            "cosmos",
+            "uatom",
+            None,
+            1.3,
+            Decimal::from_str("0.025").unwrap(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn keystore_round_trip_restores_the_signer() {
+        let signer = signer();
+        let json = signer.encrypt_with_log_n("password", TEST_LOG_N).unwrap();
+
+        let decrypted = Signer::decrypt_from_json(&json, "password").unwrap();
+        assert_eq!(decrypted.public_address, signer.public_address);
+        assert_eq!(decrypted.denom, signer.denom);
+        assert_eq!(decrypted.gas_price, signer.gas_price);
+    }
+
+    #[test]
+    fn keystore_rejects_a_wrong_password() {
+        let json = signer().encrypt_with_log_n("password", TEST_LOG_N).unwrap();
+
+        assert!(matches!(
+            Signer::decrypt_from_json(&json, "wrong password"),
+            Err(Error::Keystore(_))
+        ));
+    }
+
+    #[test]
+    fn keystore_rejects_a_tampered_ciphertext() {
+        let json = signer().encrypt_with_log_n("password", TEST_LOG_N).unwrap();
+        let mut keystore: Keystore = serde_json::from_str(&json).unwrap();
+        let mut ciphertext = hex::decode(&keystore.crypto.ciphertext).unwrap();
+        ciphertext[0] ^= 1;
+        keystore.crypto.ciphertext = hex::encode(ciphertext);
+        let json = serde_json::to_string(&keystore).unwrap();
+
+        assert!(matches!(
+            Signer::decrypt_from_json(&json, "password"),
+            Err(Error::Keystore(_))
+        ));
+    }
+}
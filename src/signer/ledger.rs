@@ -0,0 +1,172 @@
+use std::fmt::Debug;
+
+use async_trait::async_trait;
+use cosmrs::bip32::DerivationPath;
+use cosmrs::crypto::PublicKey;
+
+use crate::error::{CosmosResult, Error};
+use crate::signer::backend::SigningBackend;
+
+/// Instruction class of the Ledger Cosmos app.
+const CLA: u8 = 0x55;
+/// Instruction signing a message with a secp256k1 key.
+const INS_SIGN_SECP256K1: u8 = 0x02;
+/// Instruction returning the secp256k1 public key and address of a path.
+const INS_GET_ADDR_SECP256K1: u8 = 0x04;
+
+/// `P1` of the first chunk of a message to sign, carrying the derivation path.
+const CHUNK_INIT: u8 = 0x00;
+/// `P1` of an intermediate chunk of a message to sign.
+const CHUNK_ADD: u8 = 0x01;
+/// `P1` of the last chunk of a message to sign.
+const CHUNK_LAST: u8 = 0x02;
+/// Maximum payload size of a single APDU.
+const CHUNK_SIZE: usize = 250;
+
+/// Status word of a successful APDU.
+const SW_OK: u16 = 0x9000;
+
+/// Transport exchanging APDUs with a Ledger device, e.g. over USB HID.
+#[async_trait]
+pub trait LedgerTransport: Debug + Send + Sync {
+    /// Sends a command APDU and returns the response, including its trailing two-byte status
+    /// word.
+    async fn exchange(&self, apdu: &[u8]) -> CosmosResult<Vec<u8>>;
+}
+
+/// Backend signing with a key held by the Cosmos app of a Ledger device.
+///
+/// The Cosmos app only signs amino JSON sign docs, which the user reviews on the device.
+#[derive(Debug)]
+pub struct LedgerBackend<L: LedgerTransport> {
+    transport: L,
+    path: Vec<u8>,
+    public_key: PublicKey,
+}
+
+impl<L: LedgerTransport> LedgerBackend<L> {
+    /// Connects to the key at `derivation` on the device, fetching its public key.
+    ///
+    /// # Arguments
+    ///
+    /// * `transport`: The transport to the device, with the Cosmos app open.
+    /// * `derivation`: The five-level derivation path, e.g. `m/44'/118'/0'/0/0`.
+    /// * `prefix`: The bech32 account prefix, required by the device to derive the address.
+    pub async fn new(transport: L, derivation: &str, prefix: &str) -> CosmosResult<Self> {
+        let path = serialize_path(derivation)?;
+
+        let mut data = vec![prefix.len() as u8];
+        data.extend_from_slice(prefix.as_bytes());
+        data.extend_from_slice(&path);
+        let resp = exchange(&transport, INS_GET_ADDR_SECP256K1, 0, &data).await?;
+
+        let key = resp
+            .get(..33)
+            .ok_or_else(|| Error::Ledger("Truncated public key response".to_string()))?;
+        let public_key = cosmrs::tendermint::PublicKey::from_raw_secp256k1(key)
+            .ok_or_else(|| Error::Ledger("Invalid public key".to_string()))?;
+
+        Ok(Self {
+            transport,
+            path,
+            public_key: public_key.into(),
+        })
+    }
+}
+
+#[async_trait]
+impl<L: LedgerTransport> SigningBackend for LedgerBackend<L> {
+    fn public_key(&self) -> PublicKey {
+        self.public_key
+    }
+
+    fn supports_direct(&self) -> bool {
+        false
+    }
+
+    async fn sign(&self, sign_bytes: &[u8]) -> CosmosResult<Vec<u8>> {
+        exchange(&self.transport, INS_SIGN_SECP256K1, CHUNK_INIT, &self.path).await?;
+
+        let chunks: Vec<&[u8]> = sign_bytes.chunks(CHUNK_SIZE).collect();
+        let mut resp = Vec::new();
+        for (i, chunk) in chunks.iter().enumerate() {
+            let p1 = if i + 1 == chunks.len() {
+                CHUNK_LAST
+            } else {
+                CHUNK_ADD
+            };
+            resp = exchange(&self.transport, INS_SIGN_SECP256K1, p1, chunk).await?;
+        }
+
+        der_to_compact(&resp)
+    }
+}
+
+/// Sends a single APDU and returns its response data, without the status word.
+async fn exchange<L: LedgerTransport>(
+    transport: &L,
+    ins: u8,
+    p1: u8,
+    data: &[u8],
+) -> CosmosResult<Vec<u8>> {
+    let mut apdu = vec![CLA, ins, p1, 0, data.len() as u8];
+    apdu.extend_from_slice(data);
+
+    let mut resp = transport.exchange(&apdu).await?;
+    if resp.len() < 2 {
+        return Err(Error::Ledger("Truncated response".to_string()));
+    }
+
+    let sw = resp.split_off(resp.len() - 2);
+    let sw = u16::from_be_bytes([sw[0], sw[1]]);
+    if sw != SW_OK {
+        return Err(Error::Ledger(format!("Device returned status {sw:#06x}")));
+    }
+
+    Ok(resp)
+}
+
+/// Serializes a five-level derivation path as the little-endian indexes the device expects.
+fn serialize_path(derivation: &str) -> CosmosResult<Vec<u8>> {
+    let path: DerivationPath = derivation.parse()?;
+    let path: Vec<u8> = path
+        .iter()
+        .flat_map(|child| child.0.to_le_bytes())
+        .collect();
+
+    if path.len() != 5 * 4 {
+        return Err(Error::Ledger(format!(
+            "Derivation path {derivation} does not have five levels"
+        )));
+    }
+
+    Ok(path)
+}
+
+/// Converts a DER encoded ECDSA signature to its 64-byte compact form.
+fn der_to_compact(der: &[u8]) -> CosmosResult<Vec<u8>> {
+    let invalid = || Error::Ledger("Invalid DER signature".to_string());
+
+    let integer = |input: &[u8]| -> Option<(Vec<u8>, usize)> {
+        let (&tag, rest) = input.split_first()?;
+        let (&len, rest) = rest.split_first()?;
+        let value = rest.get(..len as usize).filter(|_| tag == 0x02)?;
+        let value: Vec<u8> = value.iter().copied().skip_while(|b| *b == 0).collect();
+        if value.len() > 32 {
+            return None;
+        }
+
+        let mut padded = vec![0u8; 32 - value.len()];
+        padded.extend_from_slice(&value);
+        Some((padded, 2 + len as usize))
+    };
+
+    let body = der
+        .get(2..)
+        .filter(|_| der[0] == 0x30)
+        .ok_or_else(invalid)?;
+    let (r, consumed) = integer(body).ok_or_else(invalid)?;
+    let (s, _) = integer(&body[consumed..]).ok_or_else(invalid)?;
+
+    Ok([r, s].concat())
+}
@@ -0,0 +1,855 @@
+pub mod adr036;
+mod amino;
+pub mod backend;
+mod keystore;
+pub mod ledger;
+pub mod multisig;
+
+use std::collections::HashSet;
+use std::fmt::Debug;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+use cosmrs::bip32::secp256k1::elliptic_curve::rand_core::OsRng;
+use cosmrs::bip32::{Language, Mnemonic, XPrv};
+use cosmrs::crypto::secp256k1::SigningKey;
+use cosmrs::crypto::PublicKey;
+use cosmrs::proto::cosmos::tx::v1beta1::TxRaw;
+use cosmrs::proto::prost::Message;
+use cosmrs::tendermint::chain;
+use cosmrs::tx::{AccountNumber, Body, SequenceNumber};
+use cosmrs::tx::{Fee, ModeInfo, SignDoc, SignerInfo, SignerPublicKey};
+use cosmrs::{AccountId, Any, Coin, Gas};
+use hex::decode;
+use k256::ecdsa::signature::hazmat::PrehashVerifier;
+use k256::ecdsa::{Signature, VerifyingKey};
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use sha3::{Digest, Keccak256};
+
+use crate::error::{CosmosResult, Error};
+use crate::signer::backend::{EthSoftwareBackend, SigningBackend, SoftwareBackend};
+use crate::signer::ledger::{LedgerBackend, LedgerTransport};
+
+/// Derivation path of the first account of the Cosmos coin type.
+const DEFAULT_DERIVATION_PATH: &str = "m/44'/118'/0'/0/0";
+
+/// Derivation path of the first account of the Ethereum coin type, used by EVM-compatible
+/// chains.
+const ETH_DERIVATION_PATH: &str = "m/44'/60'/0'/0/0";
+
+/// Type of the key of a signer.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum KeyType {
+    /// Cosmos `secp256k1` key, with RIPEMD160(SHA256) addresses and SHA256 signed digests.
+    #[default]
+    Secp256k1,
+    /// Ethermint `eth_secp256k1` key of EVM-compatible chains such as Evmos, with Keccak
+    /// addresses and signed digests.
+    EthSecp256k1,
+    /// Injective's `eth_secp256k1` key, identical to `EthSecp256k1` but with its own public key
+    /// type URL.
+    InjectiveEthSecp256k1,
+}
+
+impl KeyType {
+    /// Returns the derivation path of the first account for this key type.
+    pub fn default_derivation(&self) -> &'static str {
+        match self {
+            KeyType::Secp256k1 => DEFAULT_DERIVATION_PATH,
+            KeyType::EthSecp256k1 | KeyType::InjectiveEthSecp256k1 => ETH_DERIVATION_PATH,
+        }
+    }
+
+    /// Returns the type URL of the public key in transactions.
+    pub fn public_key_type_url(&self) -> &'static str {
+        match self {
+            KeyType::Secp256k1 => "/cosmos.crypto.secp256k1.PubKey",
+            KeyType::EthSecp256k1 => "/ethermint.crypto.v1.ethsecp256k1.PubKey",
+            KeyType::InjectiveEthSecp256k1 => "/injective.crypto.v1beta1.ethsecp256k1.PubKey",
+        }
+    }
+
+    /// Hashes sign bytes into the digest signed by keys of this type: SHA256 for Cosmos keys
+    /// and Keccak256 for Ethereum-style keys.
+    fn digest(&self, message: &[u8]) -> Vec<u8> {
+        match self {
+            KeyType::Secp256k1 => Sha256::digest(message).to_vec(),
+            KeyType::EthSecp256k1 | KeyType::InjectiveEthSecp256k1 => {
+                Keccak256::digest(message).to_vec()
+            }
+        }
+    }
+
+    /// Derives the bech32 address of a public key.
+    pub fn account_id(&self, public_key: &PublicKey, prefix: &str) -> CosmosResult<AccountId> {
+        match self {
+            KeyType::Secp256k1 => Ok(public_key.account_id(prefix)?),
+            KeyType::EthSecp256k1 | KeyType::InjectiveEthSecp256k1 => {
+                let key = VerifyingKey::from_sec1_bytes(&public_key.to_bytes())
+                    .map_err(|e| Error::wrap("Invalid secp256k1 public key", e))?;
+                let point = key.to_encoded_point(false);
+                let hash = Keccak256::digest(&point.as_bytes()[1..]);
+                Ok(AccountId::new(prefix, &hash[12..])?)
+            }
+        }
+    }
+}
+
+/// Locally tracked sequence number shared by every clone of a `Signer`, but not by signers
+/// created with `Signer::ephemeral`.
+#[derive(Debug, Clone)]
+pub struct SequenceLane {
+    next: Arc<AtomicU64>,
+}
+
+impl SequenceLane {
+    /// Creates a new lane whose next transaction uses `sequence`.
+    pub fn new(sequence: SequenceNumber) -> Self {
+        Self {
+            next: Arc::new(AtomicU64::new(sequence)),
+        }
+    }
+
+    /// Returns the sequence the next transaction of this lane will use.
+    pub fn peek(&self) -> SequenceNumber {
+        self.next.load(Ordering::SeqCst)
+    }
+
+    /// Sets the sequence the next transaction of this lane will use.
+    pub fn reset(&self, sequence: SequenceNumber) {
+        self.next.store(sequence, Ordering::SeqCst);
+    }
+
+    /// Marks the current sequence as used and returns the following one.
+    pub fn advance(&self) -> SequenceNumber {
+        self.next.fetch_add(1, Ordering::SeqCst) + 1
+    }
+
+    /// Marks `sequence` as used, moving the lane past it unless it already is.
+    pub fn advance_past(&self, sequence: SequenceNumber) {
+        self.next.fetch_max(sequence + 1, Ordering::SeqCst);
+    }
+}
+
+/// Sign mode used to produce transaction signatures.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SignMode {
+    /// `SIGN_MODE_DIRECT`, signing the protobuf encoded sign doc.
+    #[default]
+    Direct,
+    /// `SIGN_MODE_LEGACY_AMINO_JSON`, signing the canonical amino JSON sign doc, as required
+    /// by hardware wallets and some multisig tooling.
+    LegacyAminoJson,
+}
+
+/// Price of one unit of gas in a fee denom.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GasPrice {
+    /// Fee denom.
+    pub denom: String,
+    /// Price per unit of gas, e.g. `0.025`.
+    pub amount: Decimal,
+}
+
+/// Which of the signer's gas prices a transaction fee is paid with.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum FeeCoins {
+    /// Pays the fee in the signer's `denom` only.
+    #[default]
+    First,
+    /// Pays the fee in the given denom, which must be `denom` or one of `extra_gas_prices`.
+    Denom(String),
+    /// Pays the full fee in every configured denom, each at its own gas price.
+    ///
+    /// This satisfies the SDK's default minimum gas price check, which requires the fee to
+    /// cover the gas limit at the gas price of every denom in the fee, at the cost of paying
+    /// the fee once per denom.
+    All,
+}
+
+/// Represents a signer with mnemonic, private key, and public key information.
+#[derive(Clone)]
+pub struct Signer {
+    /// Mnemonic phrase (optional).
+    pub mnemonic: Option<String>,
+    /// Derivation path of the key, if derived from `mnemonic`.
+    pub derivation: Option<String>,
+    /// Denomination of the cryptocurrency.
+    pub denom: String,
+    /// Public address associated with the signer.
+    pub public_address: AccountId,
+    /// Private key for signing transactions, if held in memory. `eth_secp256k1` keys are only
+    /// held by `backend`.
+    pub private_key: Option<Arc<SigningKey>>,
+    /// Backend producing the signatures, holding either `private_key` or an external key.
+    pub backend: Arc<dyn SigningBackend>,
+    /// Public key associated with the private key.
+    pub public_key: PublicKey,
+    /// Multiplier applied to the simulated gas usage to get the gas limit, e.g. `1.3`.
+    pub gas_adjustment: f64,
+    /// Price per unit of gas, in `denom`, e.g. `0.025`.
+    pub gas_price: Decimal,
+    /// Locally tracked sequence used instead of the on-chain account sequence, if any.
+    pub sequence_lane: Option<SequenceLane>,
+    /// Sign mode of the transactions signed by this signer.
+    pub sign_mode: SignMode,
+    /// Account paying the fees of this signer's transactions through a fee allowance, if any.
+    pub fee_granter: Option<AccountId>,
+    /// Gas prices of the fee denoms accepted in addition to `denom`.
+    pub extra_gas_prices: Vec<GasPrice>,
+    /// Which denoms the fee is paid in.
+    pub fee_coins: FeeCoins,
+    /// Type of the signing key, determining the address and public key encoding.
+    pub key_type: KeyType,
+}
+
+impl Signer {
+    /// Generates a new signer with a random mnemonic phrase.
+    pub fn generate_signer(
+        prefix: &str,
+        denom: &str,
+        derivation: Option<&str>,
+        gas_adjustment: f64,
+        gas_price: Decimal,
+    ) -> CosmosResult<Self> {
+        let mnemonic = Mnemonic::random(OsRng, Language::English);
+        Signer::from_mnemonic(
+            mnemonic.phrase(),
+            prefix,
+            denom,
+            derivation,
+            gas_adjustment,
+            gas_price,
+        )
+    }
+
+    /// Creates a signer from a provided private key.
+    pub fn from_private_key(
+        private_key: &str,
+        prefix: &str,
+        denom: &str,
+        gas_adjustment: f64,
+        gas_price: Decimal,
+    ) -> CosmosResult<Self> {
+        let private_key = Arc::new(SigningKey::from_slice(decode(private_key)?.as_slice())?);
+        let public_key = private_key.public_key();
+        let public_address = public_key.account_id(prefix)?;
+
+        Ok(Signer {
+            public_address,
+            gas_adjustment: validate_gas_adjustment(gas_adjustment)?,
+            gas_price,
+            public_key,
+            mnemonic: None,
+            derivation: None,
+            denom: denom.to_string(),
+            private_key: Some(private_key.clone()),
+            backend: Arc::new(SoftwareBackend::new(private_key)),
+            sequence_lane: None,
+            sign_mode: SignMode::Direct,
+            fee_granter: None,
+            extra_gas_prices: Vec::new(),
+            fee_coins: FeeCoins::First,
+            key_type: KeyType::Secp256k1,
+        })
+    }
+
+    /// Creates a signer from a provided mnemonic phrase.
+    pub fn from_mnemonic(
+        phrase: &str,
+        prefix: &str,
+        denom: &str,
+        derivation: Option<&str>,
+        gas_adjustment: f64,
+        gas_price: Decimal,
+    ) -> CosmosResult<Self> {
+        Signer::from_mnemonic_with_key_type(
+            phrase,
+            prefix,
+            denom,
+            derivation,
+            KeyType::Secp256k1,
+            gas_adjustment,
+            gas_price,
+        )
+    }
+
+    /// Creates a signer of the given key type from a provided mnemonic phrase.
+    ///
+    /// The derivation path defaults to the key type's, e.g. `m/44'/60'/0'/0/0` for
+    /// `KeyType::EthSecp256k1`.
+    pub fn from_mnemonic_with_key_type(
+        phrase: &str,
+        prefix: &str,
+        denom: &str,
+        derivation: Option<&str>,
+        key_type: KeyType,
+        gas_adjustment: f64,
+        gas_price: Decimal,
+    ) -> CosmosResult<Self> {
+        let derivation = derivation.unwrap_or(key_type.default_derivation());
+        validate_derivation_path(derivation)?;
+        let mnemonic = Mnemonic::new(phrase, Language::English)?;
+        let pri = XPrv::derive_from_path(&mnemonic.to_seed(""), &derivation.parse()?)?;
+
+        let (private_key, backend): (Option<Arc<SigningKey>>, Arc<dyn SigningBackend>) =
+            match key_type {
+                KeyType::Secp256k1 => {
+                    let private_key = Arc::new(SigningKey::from(pri));
+                    (
+                        Some(private_key.clone()),
+                        Arc::new(SoftwareBackend::new(private_key)),
+                    )
+                }
+                KeyType::EthSecp256k1 | KeyType::InjectiveEthSecp256k1 => (
+                    None,
+                    Arc::new(EthSoftwareBackend::new(pri.private_key().clone())?),
+                ),
+            };
+        let public_key = backend.public_key();
+        let public_address = key_type.account_id(&public_key, prefix)?;
+
+        Ok(Signer {
+            mnemonic: Some(phrase.to_string()),
+            derivation: Some(derivation.to_string()),
+            public_address,
+            gas_adjustment: validate_gas_adjustment(gas_adjustment)?,
+            gas_price,
+            public_key,
+            denom: denom.to_string(),
+            private_key,
+            backend,
+            sequence_lane: None,
+            sign_mode: SignMode::Direct,
+            fee_granter: None,
+            extra_gas_prices: Vec::new(),
+            fee_coins: FeeCoins::First,
+            key_type,
+        })
+    }
+
+    /// Creates a signer from a provided mnemonic phrase, with the gas adjustment given as a
+    /// percentage added to the simulated gas, e.g. `30` for a `1.3` multiplier.
+    #[deprecated(note = "use `from_mnemonic` with a gas adjustment multiplier")]
+    pub fn from_mnemonic_percent(
+        phrase: &str,
+        prefix: &str,
+        denom: &str,
+        derivation: Option<&str>,
+        gas_adjustment_percent: u8,
+        gas_price: Decimal,
+    ) -> CosmosResult<Self> {
+        Signer::from_mnemonic(
+            phrase,
+            prefix,
+            denom,
+            derivation,
+            1.0 + f64::from(gas_adjustment_percent) / 100.0,
+            gas_price,
+        )
+    }
+
+    /// Creates a signer from a provided mnemonic phrase, for the account at `account_index`
+    /// of the default Cosmos derivation path `m/44'/118'/0'/0/{account_index}`.
+    ///
+    /// # Errors
+    ///
+    /// * Returns `Error::InvalidDerivationPath` if `account_index` is not below 2^31.
+    pub fn from_mnemonic_account(
+        phrase: &str,
+        prefix: &str,
+        denom: &str,
+        account_index: u32,
+        gas_adjustment: f64,
+        gas_price: Decimal,
+    ) -> CosmosResult<Self> {
+        let derivation = format!("m/44'/118'/0'/0/{account_index}");
+        Signer::from_mnemonic(
+            phrase,
+            prefix,
+            denom,
+            Some(&derivation),
+            gas_adjustment,
+            gas_price,
+        )
+    }
+
+    /// Derives the signers of the first `count` accounts of a mnemonic phrase, as returned by
+    /// `from_mnemonic_account` for the indices `0..count`.
+    pub fn derive_accounts(
+        phrase: &str,
+        prefix: &str,
+        denom: &str,
+        count: u32,
+        gas_adjustment: f64,
+        gas_price: Decimal,
+    ) -> CosmosResult<Vec<Self>> {
+        (0..count)
+            .map(|index| {
+                Signer::from_mnemonic_account(
+                    phrase,
+                    prefix,
+                    denom,
+                    index,
+                    gas_adjustment,
+                    gas_price,
+                )
+            })
+            .collect()
+    }
+
+    /// Creates a signer backed by the Cosmos app of a Ledger device, fetching the public key
+    /// and address of `derivation` from the device.
+    ///
+    /// The private key never leaves the device, and every transaction must be confirmed on it.
+    pub async fn from_ledger<L: LedgerTransport + 'static>(
+        transport: L,
+        prefix: &str,
+        denom: &str,
+        derivation: Option<&str>,
+        gas_adjustment: f64,
+        gas_price: Decimal,
+    ) -> CosmosResult<Self> {
+        let derivation = derivation.unwrap_or(DEFAULT_DERIVATION_PATH);
+        let backend = LedgerBackend::new(transport, derivation, prefix).await?;
+        let public_key = backend.public_key();
+        let public_address = public_key.account_id(prefix)?;
+
+        Ok(Signer {
+            mnemonic: None,
+            derivation: None,
+            public_address,
+            gas_adjustment: validate_gas_adjustment(gas_adjustment)?,
+            gas_price,
+            public_key,
+            denom: denom.to_string(),
+            private_key: None,
+            backend: Arc::new(backend),
+            sequence_lane: None,
+            sign_mode: SignMode::LegacyAminoJson,
+            fee_granter: None,
+            extra_gas_prices: Vec::new(),
+            fee_coins: FeeCoins::First,
+            key_type: KeyType::Secp256k1,
+        })
+    }
+
+    /// Creates a copy of this signer sharing the same key but driving its own sequence lane,
+    /// starting at `sequence`.
+    ///
+    /// Each worker of a pool can own one ephemeral signer so that concurrent requests never
+    /// race on a shared sequence. The caller is responsible for assigning non-overlapping
+    /// starting sequences, e.g. from the on-chain account sequence.
+    pub fn ephemeral(&self, sequence: SequenceNumber) -> Self {
+        Self {
+            sequence_lane: Some(SequenceLane::new(sequence)),
+            ..self.clone()
+        }
+    }
+
+    /// Signs a transaction, paying `fee` if set, otherwise the signer's fee for `gas_info`.
+    pub async fn sign(
+        &mut self,
+        chain_id: &str,
+        account_number: AccountNumber,
+        sequence_id: SequenceNumber,
+        gas_info: Gas,
+        fee: Option<Fee>,
+        body: Body,
+    ) -> CosmosResult<Vec<u8>> {
+        let fee = match fee {
+            Some(fee) => fee,
+            None => self.fee(gas_info)?,
+        };
+        self.sign_with_fee(chain_id, account_number, sequence_id, fee, body)
+            .await
+    }
+
+    /// Accepts fees in additional denoms, and selects which denoms are paid.
+    pub fn with_gas_prices(mut self, extra_gas_prices: Vec<GasPrice>, fee_coins: FeeCoins) -> Self {
+        self.extra_gas_prices = extra_gas_prices;
+        self.fee_coins = fee_coins;
+        self
+    }
+
+    /// Returns every gas price of the signer, starting with `denom`'s.
+    pub fn gas_prices(&self) -> Vec<GasPrice> {
+        let primary = GasPrice {
+            denom: self.denom.clone(),
+            amount: self.gas_price,
+        };
+
+        std::iter::once(primary)
+            .chain(self.extra_gas_prices.iter().cloned())
+            .collect()
+    }
+
+    /// Builds the fee paid by this signer for a transaction with the given gas limit, i.e. the
+    /// gas price times the gas limit, rounded up, in the denoms selected by `fee_coins`.
+    ///
+    /// With `FeeCoins::All`, the full fee is charged in each distinct denom. Coins are sorted by
+    /// denom, as the SDK requires.
+    ///
+    /// # Errors
+    ///
+    /// * Returns `Error::Custom` if `fee_coins` selects a denom without a gas price.
+    pub fn fee(&self, gas_info: Gas) -> CosmosResult<Fee> {
+        let mut prices = self.gas_prices();
+        match &self.fee_coins {
+            FeeCoins::First => prices.truncate(1),
+            FeeCoins::Denom(denom) => {
+                let index = prices
+                    .iter()
+                    .position(|price| &price.denom == denom)
+                    .ok_or_else(|| Error::Custom(format!("No gas price for fee denom {denom}")))?;
+                prices = vec![prices.swap_remove(index)];
+            }
+            FeeCoins::All => {
+                // The first price configured for a denom wins.
+                let mut seen = HashSet::new();
+                prices.retain(|price| seen.insert(price.denom.clone()));
+                prices.sort_by(|a, b| a.denom.cmp(&b.denom));
+            }
+        }
+
+        let amount = prices
+            .iter()
+            .map(|price| fee_coin(price, gas_info))
+            .collect::<CosmosResult<Vec<_>>>()?;
+
+        Ok(Fee {
+            amount,
+            gas_limit: gas_info,
+            payer: None,
+            granter: None,
+        })
+    }
+
+    /// Signs a transaction paying exactly the provided fee.
+    ///
+    /// If the fee does not name a granter, the signer's `fee_granter` pays it.
+    pub async fn sign_with_fee(
+        &mut self,
+        chain_id: &str,
+        account_number: AccountNumber,
+        sequence_id: SequenceNumber,
+        mut fee: Fee,
+        body: Body,
+    ) -> CosmosResult<Vec<u8>> {
+        if fee.granter.is_none() {
+            fee.granter = self.fee_granter.clone();
+        }
+
+        match self.sign_mode {
+            SignMode::Direct => {
+                self.sign_direct(chain_id, account_number, sequence_id, fee, body)
+                    .await
+            }
+            SignMode::LegacyAminoJson => {
+                self.sign_amino_json(chain_id, account_number, sequence_id, fee, body)
+                    .await
+            }
+        }
+    }
+
+    /// Signs a transaction with `SIGN_MODE_DIRECT`.
+    async fn sign_direct(
+        &self,
+        chain_id: &str,
+        account_number: AccountNumber,
+        sequence_id: SequenceNumber,
+        fee: Fee,
+        body: Body,
+    ) -> CosmosResult<Vec<u8>> {
+        if !self.backend.supports_direct() {
+            return Err(Error::Custom(
+                "Signing backend does not support SIGN_MODE_DIRECT".to_string(),
+            ));
+        }
+
+        let auth_info = SignerInfo {
+            public_key: Some(self.signer_public_key()),
+            mode_info: ModeInfo::single(cosmrs::tx::SignMode::Direct),
+            sequence: sequence_id,
+        }
+        .auth_info(fee);
+
+        let sign_doc = SignDoc::new(
+            &body,
+            &auth_info,
+            &chain::Id::from_str(chain_id)?,
+            account_number,
+        )?;
+
+        let signature = self.backend.sign(&sign_doc.clone().into_bytes()?).await?;
+        Ok(TxRaw {
+            body_bytes: sign_doc.body_bytes,
+            auth_info_bytes: sign_doc.auth_info_bytes,
+            signatures: vec![signature],
+        }
+        .encode_to_vec())
+    }
+
+    /// Signs a transaction with `SIGN_MODE_LEGACY_AMINO_JSON`.
+    async fn sign_amino_json(
+        &self,
+        chain_id: &str,
+        account_number: AccountNumber,
+        sequence_id: SequenceNumber,
+        fee: Fee,
+        body: Body,
+    ) -> CosmosResult<Vec<u8>> {
+        let sign_doc = amino::std_sign_doc(chain_id, account_number, sequence_id, &fee, &body)?;
+        let signature = self.backend.sign(&sign_doc).await?;
+
+        let auth_info = SignerInfo {
+            public_key: Some(self.signer_public_key()),
+            mode_info: ModeInfo::single(cosmrs::tx::SignMode::LegacyAminoJson),
+            sequence: sequence_id,
+        }
+        .auth_info(fee);
+
+        Ok(TxRaw {
+            body_bytes: body.into_bytes()?,
+            auth_info_bytes: auth_info.into_bytes()?,
+            signatures: vec![signature],
+        }
+        .encode_to_vec())
+    }
+
+    /// Returns the public key as encoded in the signer infos of transactions.
+    pub(crate) fn signer_public_key(&self) -> SignerPublicKey {
+        match self.key_type {
+            KeyType::Secp256k1 => SignerPublicKey::Single(self.public_key),
+            KeyType::EthSecp256k1 | KeyType::InjectiveEthSecp256k1 => SignerPublicKey::Any(Any {
+                type_url: self.key_type.public_key_type_url().to_string(),
+                value: cosmrs::proto::cosmos::crypto::secp256k1::PubKey {
+                    key: self.public_key.to_bytes(),
+                }
+                .encode_to_vec(),
+            }),
+        }
+    }
+
+    /// Verifies a 64-byte compact signature over `message` against the signer's public key,
+    /// hashing the message as the signer's key type does when signing.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the signature is valid, `false` otherwise.
+    ///
+    /// # Errors
+    ///
+    /// * Returns an error if the signature is not a 64-byte compact signature.
+    pub fn verify(&self, message: &[u8], signature: &[u8]) -> CosmosResult<bool> {
+        verify_digest(&self.public_key, &self.key_type.digest(message), signature)
+    }
+}
+
+/// Verifies a 64-byte compact secp256k1 signature over the SHA256 digest of `message`, as
+/// produced by Cosmos wallets, against `public_key`.
+///
+/// Signatures with a high S value are rejected, as they are by the chain.
+///
+/// # Returns
+///
+/// `true` if the signature is valid, `false` otherwise.
+///
+/// # Errors
+///
+/// * Returns an error if the public key is not a secp256k1 key or the signature is not a
+///   64-byte compact signature.
+pub fn verify_with_pubkey(
+    public_key: &PublicKey,
+    message: &[u8],
+    signature: &[u8],
+) -> CosmosResult<bool> {
+    verify_digest(public_key, &KeyType::Secp256k1.digest(message), signature)
+}
+
+/// Verifies a compact secp256k1 signature over an already hashed message.
+fn verify_digest(public_key: &PublicKey, digest: &[u8], signature: &[u8]) -> CosmosResult<bool> {
+    if public_key.type_url() != PublicKey::SECP256K1_TYPE_URL {
+        return Err(Error::Custom(format!(
+            "Cannot verify a signature with a {} public key",
+            public_key.type_url()
+        )));
+    }
+
+    let key = VerifyingKey::from_sec1_bytes(&public_key.to_bytes())
+        .map_err(|e| Error::wrap("Invalid secp256k1 public key", e))?;
+    let signature = Signature::from_slice(signature)
+        .map_err(|e| Error::wrap("Invalid secp256k1 signature", e))?;
+
+    Ok(key.verify_prehash(digest, &signature).is_ok())
+}
+
+/// Computes the fee coin paying `gas_limit` at `price`, rounded up.
+fn fee_coin(price: &GasPrice, gas_limit: Gas) -> CosmosResult<Coin> {
+    let amount = price
+        .amount
+        .checked_mul(Decimal::from(gas_limit))
+        .and_then(|amount| amount.ceil().to_u128())
+        .ok_or_else(|| {
+            Error::Custom(format!(
+                "Invalid fee for gas price {}{} and gas {gas_limit}",
+                price.amount, price.denom
+            ))
+        })?;
+
+    Ok(Coin {
+        amount,
+        denom: price.denom.parse()?,
+    })
+}
+
+/// Returns `Error::InvalidGasAdjustment` unless the gas adjustment is a finite multiplier of
+/// at least 1.0.
+fn validate_gas_adjustment(gas_adjustment: f64) -> CosmosResult<f64> {
+    if !gas_adjustment.is_finite() || gas_adjustment < 1.0 {
+        return Err(Error::InvalidGasAdjustment(gas_adjustment));
+    }
+
+    Ok(gas_adjustment)
+}
+
+/// Checks that a BIP-32 derivation path is `m` followed by `/`-separated indices below 2^31,
+/// each optionally hardened with `'`.
+fn validate_derivation_path(path: &str) -> CosmosResult<()> {
+    let invalid = |reason: String| Error::InvalidDerivationPath {
+        path: path.to_string(),
+        reason,
+    };
+
+    let mut components = path.split('/');
+    if components.next() != Some("m") {
+        return Err(invalid("path must start with \"m\"".to_string()));
+    }
+
+    for component in components {
+        let index = component.strip_suffix('\'').unwrap_or(component);
+        let index = index
+            .parse::<u32>()
+            .map_err(|_| invalid(format!("component {component:?} is not an index")))?;
+        if index >= 1 << 31 {
+            return Err(invalid(format!(
+                "index {index} must be below 2^31, hardened indices are marked with '"
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+/// Redacts the mnemonic and private key.
+impl Debug for Signer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Signer")
+            .field("public_address", &self.public_address)
+            .field("denom", &self.denom)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PRIVATE_KEY: &str = "0101010101010101010101010101010101010101010101010101010101010101";
+
+    fn price(amount: &str, denom: &str) -> GasPrice {
+        GasPrice {
+            denom: denom.to_string(),
+            amount: Decimal::from_str(amount).unwrap(),
+        }
+    }
+
+    fn coin(amount: u128, denom: &str) -> Coin {
+        Coin {
+            amount,
+            denom: denom.parse().unwrap(),
+        }
+    }
+
+    fn signer(gas_price: &str) -> Signer {
+        Signer::from_private_key(
+            PRIVATE_KEY,
+            "cosmos",
+            "uatom",
+            1.3,
+            Decimal::from_str(gas_price).unwrap(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn fee_coin_is_exact_when_the_product_is_whole() {
+        let fee = fee_coin(&price("0.025", "uatom"), 200_000).unwrap();
+        assert_eq!(fee, coin(5_000, "uatom"));
+    }
+
+    #[test]
+    fn fee_coin_rounds_up() {
+        let fee = fee_coin(&price("0.025", "uatom"), 100_001).unwrap();
+        assert_eq!(fee, coin(2_501, "uatom"));
+    }
+
+    #[test]
+    fn fee_coin_charges_fractional_prices() {
+        assert_eq!(
+            fee_coin(&price("0.0001", "uatom"), 12_345).unwrap(),
+            coin(2, "uatom")
+        );
+        assert_eq!(
+            fee_coin(&price("0.000001", "uatom"), 1).unwrap(),
+            coin(1, "uatom")
+        );
+        assert_eq!(
+            fee_coin(&price("0", "uatom"), 200_000).unwrap(),
+            coin(0, "uatom")
+        );
+    }
+
+    #[test]
+    fn fee_pays_the_primary_denom_by_default() {
+        let fee = signer("0.025").fee(100_001).unwrap();
+        assert_eq!(fee.amount, vec![coin(2_501, "uatom")]);
+        assert_eq!(fee.gas_limit, 100_001);
+    }
+
+    #[test]
+    fn fee_pays_the_selected_denom() {
+        let signer = signer("0.025").with_gas_prices(
+            vec![price("0.5", "ustake")],
+            FeeCoins::Denom("ustake".to_string()),
+        );
+        let fee = signer.fee(3).unwrap();
+        assert_eq!(fee.amount, vec![coin(2, "ustake")]);
+    }
+
+    #[test]
+    fn fee_rejects_a_denom_without_a_gas_price() {
+        let signer =
+            signer("0.025").with_gas_prices(Vec::new(), FeeCoins::Denom("ustake".to_string()));
+        assert!(signer.fee(100_000).is_err());
+    }
+
+    #[test]
+    fn fee_charges_every_sorted_distinct_denom_in_full() {
+        let signer = signer("0.025").with_gas_prices(
+            vec![
+                price("0.05", "stake"),
+                price("1", "uatom"),
+                price("0.05", "stake"),
+            ],
+            FeeCoins::All,
+        );
+        let fee = signer.fee(100_001).unwrap();
+        assert_eq!(fee.amount, vec![coin(5_001, "stake"), coin(2_501, "uatom")]);
+    }
+}
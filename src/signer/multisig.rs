@@ -0,0 +1,292 @@
+use cosmrs::crypto::PublicKey;
+use cosmrs::proto::cosmos::crypto::multisig::v1beta1::{CompactBitArray, MultiSignature};
+use cosmrs::proto::cosmos::crypto::multisig::LegacyAminoPubKey;
+use cosmrs::proto::cosmos::crypto::secp256k1::PubKey;
+use cosmrs::proto::cosmos::tx::signing::v1beta1::SignMode as ProtoSignMode;
+use cosmrs::proto::cosmos::tx::v1beta1::mode_info::{Multi, Single, Sum};
+use cosmrs::proto::cosmos::tx::v1beta1::{AuthInfo, ModeInfo, SignerInfo, TxRaw};
+use cosmrs::proto::prost::encoding::encode_varint;
+use cosmrs::proto::prost::Message;
+use cosmrs::tx::{AccountNumber, Body, Fee, SequenceNumber};
+use cosmrs::{AccountId, Any};
+use sha2::{Digest, Sha256};
+
+use crate::error::{CosmosResult, Error};
+use crate::signer::{amino, Signer};
+
+/// Amino prefix of a `tendermint/PubKeyMultisigThreshold` public key.
+const AMINO_MULTISIG_PREFIX: [u8; 4] = [0x22, 0xc1, 0xf7, 0xe2];
+
+/// Amino prefix of a `tendermint/PubKeySecp256k1` public key, followed by its length.
+const AMINO_SECP256K1_PREFIX: [u8; 5] = [0xeb, 0x5a, 0xe9, 0x87, 0x21];
+
+/// Signature of one member of a multisig account.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartialSignature {
+    /// Public key of the member.
+    pub public_key: PublicKey,
+    /// The member's signature over the amino JSON sign doc of the transaction.
+    pub signature: Vec<u8>,
+}
+
+/// Legacy amino threshold multisig account, `threshold` of whose secp256k1 members must sign
+/// a transaction.
+///
+/// Members sign the amino JSON sign doc with `sign_partial`, and any party assembles the
+/// signatures with `combine`.
+#[derive(Debug, Clone)]
+pub struct MultisigSigner {
+    /// Number of member signatures required.
+    pub threshold: u32,
+    /// Public keys of the members, in the order they were registered on chain.
+    pub public_keys: Vec<PublicKey>,
+    /// Address of the multisig account.
+    pub address: AccountId,
+}
+
+impl MultisigSigner {
+    /// Creates a multisig account from its threshold and ordered member public keys.
+    ///
+    /// # Errors
+    ///
+    /// * Returns `Error::Custom` if the threshold is zero or exceeds the number of members.
+    pub fn new(threshold: u32, public_keys: Vec<PublicKey>, prefix: &str) -> CosmosResult<Self> {
+        if threshold == 0 || threshold as usize > public_keys.len() {
+            return Err(Error::Custom(format!(
+                "Invalid multisig threshold {threshold} for {} members",
+                public_keys.len()
+            )));
+        }
+
+        let hash = Sha256::digest(amino_multisig_bytes(threshold, &public_keys));
+        let address = AccountId::new(prefix, &hash[..20])?;
+
+        Ok(Self {
+            threshold,
+            public_keys,
+            address,
+        })
+    }
+
+    /// Returns the multisig public key, as encoded in transactions.
+    pub fn public_key(&self) -> LegacyAminoPubKey {
+        LegacyAminoPubKey {
+            threshold: self.threshold,
+            public_keys: self.public_keys.iter().map(secp256k1_any).collect(),
+        }
+    }
+
+    /// Signs a transaction of the multisig account as one of its members.
+    ///
+    /// # Arguments
+    ///
+    /// * `member` - The signer of a member of the multisig.
+    /// * `account_number` - The account number of the multisig account.
+    /// * `sequence` - The sequence of the multisig account.
+    ///
+    /// # Errors
+    ///
+    /// * Returns `Error::Custom` if `member` is not a member of the multisig.
+    pub async fn sign_partial(
+        &self,
+        member: &Signer,
+        chain_id: &str,
+        account_number: AccountNumber,
+        sequence: SequenceNumber,
+        fee: &Fee,
+        body: &Body,
+    ) -> CosmosResult<PartialSignature> {
+        self.member_index(&member.public_key)?;
+
+        let sign_doc = amino::std_sign_doc(chain_id, account_number, sequence, fee, body)?;
+        let signature = member.backend.sign(&sign_doc).await?;
+
+        Ok(PartialSignature {
+            public_key: member.public_key,
+            signature,
+        })
+    }
+
+    /// Assembles member signatures into the signed `TxRaw` bytes of the transaction.
+    ///
+    /// The fee, body and sequence must be the ones the members signed. Signatures can be given
+    /// in any order; duplicates are ignored.
+    ///
+    /// # Errors
+    ///
+    /// * Returns `Error::Custom` if a signature is not from a member, or if fewer than
+    ///   `threshold` distinct members signed.
+    pub fn combine(
+        &self,
+        body: Body,
+        fee: Fee,
+        sequence: SequenceNumber,
+        signatures: Vec<PartialSignature>,
+    ) -> CosmosResult<Vec<u8>> {
+        let mut signed = vec![None; self.public_keys.len()];
+        for partial in signatures {
+            let index = self.member_index(&partial.public_key)?;
+            signed[index].get_or_insert(partial.signature);
+        }
+
+        let count = signed
+            .iter()
+            .filter(|signature| signature.is_some())
+            .count();
+        if count < self.threshold as usize {
+            return Err(Error::Custom(format!(
+                "Multisig requires {} signatures, got {count}",
+                self.threshold
+            )));
+        }
+
+        let mut bitarray = CompactBitArray {
+            extra_bits_stored: (signed.len() % 8) as u32,
+            elems: vec![0; signed.len().div_ceil(8)],
+        };
+        for (index, _) in signed.iter().enumerate().filter(|(_, s)| s.is_some()) {
+            bitarray.elems[index / 8] |= 1 << (7 - index % 8);
+        }
+
+        let amino_mode = ModeInfo {
+            sum: Some(Sum::Single(Single {
+                mode: ProtoSignMode::LegacyAminoJson as i32,
+            })),
+        };
+        let auth_info = AuthInfo {
+            signer_infos: vec![SignerInfo {
+                public_key: Some(Any::from_msg(&self.public_key())?),
+                mode_info: Some(ModeInfo {
+                    sum: Some(Sum::Multi(Multi {
+                        bitarray: Some(bitarray),
+                        mode_infos: vec![amino_mode; count],
+                    })),
+                }),
+                sequence,
+            }],
+            fee: Some(fee.into()),
+            tip: None,
+        };
+        let multi_signature = MultiSignature {
+            signatures: signed.into_iter().flatten().collect(),
+        };
+
+        Ok(TxRaw {
+            body_bytes: body.into_bytes()?,
+            auth_info_bytes: auth_info.encode_to_vec(),
+            signatures: vec![multi_signature.encode_to_vec()],
+        }
+        .encode_to_vec())
+    }
+
+    /// Returns the position of a member in the multisig.
+    fn member_index(&self, public_key: &PublicKey) -> CosmosResult<usize> {
+        self.public_keys
+            .iter()
+            .position(|key| key == public_key)
+            .ok_or_else(|| Error::Custom("Public key is not a member of the multisig".to_string()))
+    }
+}
+
+/// Encodes a secp256k1 public key as a protobuf `Any`.
+fn secp256k1_any(public_key: &PublicKey) -> Any {
+    Any {
+        type_url: "/cosmos.crypto.secp256k1.PubKey".to_string(),
+        value: PubKey {
+            key: public_key.to_bytes(),
+        }
+        .encode_to_vec(),
+    }
+}
+
+/// Amino encodes a threshold multisig public key, whose truncated SHA256 is its address.
+fn amino_multisig_bytes(threshold: u32, public_keys: &[PublicKey]) -> Vec<u8> {
+    let mut bytes = AMINO_MULTISIG_PREFIX.to_vec();
+    bytes.push(0x08);
+    encode_varint(u64::from(threshold), &mut bytes);
+
+    for public_key in public_keys {
+        let mut key = AMINO_SECP256K1_PREFIX.to_vec();
+        key.extend(public_key.to_bytes());
+        bytes.push(0x12);
+        encode_varint(key.len() as u64, &mut bytes);
+        bytes.extend(key);
+    }
+
+    bytes
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmrs::tx::BodyBuilder;
+
+    use super::*;
+
+    /// Members of the 2-of-3 `testgroup1` multisig of the cosmjs test vectors, created with
+    /// `wasmd keys add --multisig`.
+    const MEMBERS: [&str; 3] = [
+        "038cb598ee54130d34f8e0818e7787aa06139a0e2d0026cadb662b55cf16859a67",
+        "02906f1bd9516c8cd3b52639322c801bf8724c1fa5e878c0e32b9bd6c0bb8b0f68",
+        "03cc93519d61b686da6f0e8cff9431e356b45f91063ed6f81f79ddd898858800f3",
+    ];
+
+    fn multisig() -> MultisigSigner {
+        let public_keys = MEMBERS
+            .iter()
+            .map(|key| PublicKey::from_raw_secp256k1(&hex::decode(key).unwrap()).unwrap())
+            .collect();
+        MultisigSigner::new(2, public_keys, "cosmos").unwrap()
+    }
+
+    #[test]
+    fn multisig_address_matches_the_known_address() {
+        assert_eq!(
+            multisig().address.to_string(),
+            "cosmos1pzf2wlat97n7rykrk7e8g8nxste6hde0fmr4e0"
+        );
+    }
+
+    #[test]
+    fn combine_marks_the_signing_members() {
+        let multisig = multisig();
+        let fee = Fee::from_amount_and_gas(
+            cosmrs::Coin {
+                amount: 5_000,
+                denom: "uatom".parse().unwrap(),
+            },
+            200_000u64,
+        );
+        let signatures = [2, 0]
+            .into_iter()
+            .map(|index| PartialSignature {
+                public_key: multisig.public_keys[index],
+                signature: vec![index as u8; 64],
+            })
+            .collect();
+
+        let tx = multisig
+            .combine(BodyBuilder::new().finish(), fee, 3, signatures)
+            .unwrap();
+
+        let tx = TxRaw::decode(tx.as_slice()).unwrap();
+        let auth_info = AuthInfo::decode(tx.auth_info_bytes.as_slice()).unwrap();
+        let Some(Sum::Multi(multi)) = auth_info.signer_infos[0]
+            .mode_info
+            .as_ref()
+            .and_then(|mode_info| mode_info.sum.clone())
+        else {
+            panic!("expected a multisig mode info");
+        };
+        assert_eq!(
+            multi.bitarray,
+            Some(CompactBitArray {
+                extra_bits_stored: 3,
+                elems: vec![0b1010_0000],
+            })
+        );
+        assert_eq!(multi.mode_infos.len(), 2);
+
+        let multi_signature = MultiSignature::decode(tx.signatures[0].as_slice()).unwrap();
+        assert_eq!(multi_signature.signatures, vec![vec![0; 64], vec![2; 64]]);
+    }
+}
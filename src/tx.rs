@@ -1,2 +1,122 @@
 pub use cosmrs::tx::Body;
 pub use cosmrs::tx::BodyBuilder;
+
+use cosmrs::crypto::PublicKey;
+use cosmrs::proto::cosmos::tx::v1beta1::TxRaw;
+use cosmrs::proto::prost::Message;
+use cosmrs::tx::{Fee, SequenceNumber, SignerInfo, SignerPublicKey};
+
+use crate::error::{CosmosResult, Error};
+
+/// Encodes a `Body` into `TxRaw` bytes carrying an empty signature, a zero fee and a zero gas
+/// limit, as accepted by simulation endpoints and external gas estimators.
+///
+/// # Arguments
+///
+/// * `body` - The transaction body.
+/// * `public_key` - The public key of the single signer.
+/// * `sequence` - The account sequence of the signer.
+pub fn encode_unsigned(
+    body: Body,
+    public_key: PublicKey,
+    sequence: SequenceNumber,
+) -> CosmosResult<Vec<u8>> {
+    encode_unsigned_with_key(body, SignerPublicKey::Single(public_key), sequence)
+}
+
+/// Like `encode_unsigned`, with the public key as encoded in signer infos, so that keys other
+/// than `cosmos.crypto.secp256k1` can be simulated.
+pub(crate) fn encode_unsigned_with_key(
+    body: Body,
+    public_key: SignerPublicKey,
+    sequence: SequenceNumber,
+) -> CosmosResult<Vec<u8>> {
+    let fee = Fee {
+        amount: vec![],
+        gas_limit: 0,
+        payer: None,
+        granter: None,
+    };
+    let auth_info = SignerInfo::single_direct(Some(public_key), sequence).auth_info(fee);
+
+    let raw = TxRaw {
+        body_bytes: body.into_bytes()?,
+        auth_info_bytes: auth_info.into_bytes()?,
+        signatures: vec![vec![]],
+    };
+
+    Ok(raw.encode_to_vec())
+}
+
+/// SDK error code registered for `ErrInsufficientFunds`.
+const SDK_INSUFFICIENT_FUNDS_CODE: u32 = 5;
+
+/// SDK error code registered for `ErrOutOfGas`.
+const SDK_OUT_OF_GAS_CODE: u32 = 11;
+
+/// SDK error code registered for `ErrWrongSequence`.
+const SDK_WRONG_SEQUENCE_CODE: u32 = 32;
+
+/// Maps the result of a broadcast or executed transaction to a typed error.
+///
+/// Well-known SDK failures become `Error::InsufficientFunds`, `Error::OutOfGas` and
+/// `Error::SequenceMismatch`; any other non-zero code becomes `Error::TxFailed`.
+///
+/// # Arguments
+///
+/// * `codespace` - The codespace of the result, e.g. `"sdk"`.
+/// * `code` - The result code, `0` on success.
+/// * `log` - The raw log of the result.
+pub fn check_tx_result(codespace: &str, code: u32, log: &str) -> CosmosResult<()> {
+    if code == 0 {
+        return Ok(());
+    }
+
+    if codespace == "sdk" {
+        let error = match code {
+            SDK_INSUFFICIENT_FUNDS_CODE => parse_insufficient_funds(log),
+            SDK_OUT_OF_GAS_CODE => Some(Error::OutOfGas),
+            SDK_WRONG_SEQUENCE_CODE => parse_sequence_mismatch(codespace, code, log)
+                .map(|(expected, got)| Error::SequenceMismatch { expected, got }),
+            _ => None,
+        };
+        if let Some(error) = error {
+            return Err(error);
+        }
+    }
+
+    Err(Error::TxFailed {
+        codespace: codespace.to_string(),
+        code,
+        log: log.to_string(),
+    })
+}
+
+/// Parses the `(expected, got)` sequences out of a wrong sequence result, whose log looks like
+/// `account sequence mismatch, expected 10, got 9: incorrect account sequence`.
+pub(crate) fn parse_sequence_mismatch(codespace: &str, code: u32, log: &str) -> Option<(u64, u64)> {
+    if codespace != "sdk" || code != SDK_WRONG_SEQUENCE_CODE {
+        return None;
+    }
+
+    let number_after = |marker: &str| -> Option<u64> {
+        let (_, rest) = log.split_once(marker)?;
+        let digits: String = rest.chars().take_while(char::is_ascii_digit).collect();
+        digits.parse().ok()
+    };
+
+    Some((number_after("expected ")?, number_after("got ")?))
+}
+
+/// Parses the needed and available amounts out of an insufficient funds log, which looks like
+/// `spendable balance 10uatom is smaller than 25uatom: insufficient funds`.
+fn parse_insufficient_funds(log: &str) -> Option<Error> {
+    let (head, tail) = log.split_once(" is smaller than ")?;
+    let available = head.rsplit([' ', ':']).next()?.trim();
+    let needed = tail.split(':').next()?.trim();
+
+    Some(Error::InsufficientFunds {
+        needed: needed.to_string(),
+        available: available.to_string(),
+    })
+}